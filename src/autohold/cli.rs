@@ -1,3 +1,8 @@
+use crate::enums::hold_sort::HoldSort;
+use crate::enums::hold_state::HoldState;
+use crate::util::duration::parse_duration;
+use crate::util::timestamp;
+use chrono::{DateTime, TimeDelta, Utc};
 use clap::{Parser, Subcommand};
 
 #[derive(Debug, Parser, PartialEq)]
@@ -14,8 +19,81 @@ pub enum AutoHoldCommand {
         #[arg(long, short)]
         user: Option<String>,
 
-        /// Filter on snapped
+        /// Filter on snapped, hides holds without held builds
         #[arg(long, short, default_value_t = false)]
         snapped: bool,
+
+        /// Filter on state
+        #[arg(long, default_value_t = HoldState::All)]
+        state: HoldState,
+
+        /// Sort holds
+        #[arg(long)]
+        sort: Option<HoldSort>,
+
+        /// Query every tenant
+        #[arg(long, default_value_t = false)]
+        all_tenants: bool,
+
+        /// Filter on owner regex
+        #[arg(long, short)]
+        owner: Option<String>,
+
+        /// Filter on project
+        #[arg(long, short)]
+        project: Option<String>,
+
+        /// Filter on job
+        #[arg(long, short)]
+        job: Option<String>,
+
+        /// Only holds fulfilled after a duration ago or a date, e.g. 2d or 2026-10-01
+        #[arg(long, value_parser = timestamp::parse_bound)]
+        since: Option<DateTime<Utc>>,
+
+        /// Only holds fulfilled before a duration ago or a date, e.g. 12h or 2026-10-02
+        #[arg(long, value_parser = timestamp::parse_bound)]
+        until: Option<DateTime<Utc>>,
+    },
+
+    /// List held nodes
+    Nodes {
+        /// Filter on requester
+        #[arg(long, short)]
+        user: Option<String>,
+
+        /// Don't use cache if any
+        #[arg(long, short, default_value_t = false)]
+        force: bool,
+    },
+
+    /// List holds with nodes expiring soon, exits 1 if any
+    Expiring {
+        /// Time window, e.g. 2h or 1d
+        #[arg(long, short, value_parser = parse_duration, default_value = "2h")]
+        within: TimeDelta,
+
+        /// Filter on requester
+        #[arg(long, short)]
+        user: Option<String>,
+    },
+
+    /// Create the hold again with a new expiration
+    Renew {
+        /// Autohold ID
+        #[arg(required = true)]
+        id: String,
+
+        /// New node expiration, e.g. 1d, defaults to the current one
+        #[arg(long, short, value_parser = parse_duration)]
+        expiration: Option<TimeDelta>,
+
+        /// Delete the old hold, its held nodes are released
+        #[arg(long, default_value_t = false)]
+        delete_old: bool,
+
+        /// Don't ask for confirmation
+        #[arg(long, short, default_value_t = false)]
+        yes: bool,
     },
 }
@@ -0,0 +1,69 @@
+use crate::autohold::expiring_parameters::Parameters;
+use crate::autohold::list_command::ListAutoHold;
+use crate::config::Config;
+use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, TimeDelta, Utc};
+use log;
+
+#[derive(Debug)]
+pub struct ExpiringAutoHold {
+    pub list: ListAutoHold,
+    pub parameters: Parameters,
+}
+
+impl ExpiringAutoHold {
+    pub fn new(config: Config) -> AnyhowResult<ExpiringAutoHold> {
+        Ok(Self {
+            list: ListAutoHold::new(config)?,
+            parameters: Parameters {
+                user: None,
+                within: 0,
+            },
+        })
+    }
+
+    pub fn user(&mut self, user: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.user = user;
+        Ok(self)
+    }
+
+    pub fn within(&mut self, within: TimeDelta) -> AnyhowResult<&mut Self> {
+        self.parameters.within = within.num_seconds();
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        self.list
+            .user(self.parameters.user.clone())?
+            .snapped(true)?
+            .runner()?
+            .filter()?;
+
+        // Only holds with nodes still alive but deleted within the window
+        let now = Utc::now();
+        // A window past the last representable time covers every hold
+        let deadline = TimeDelta::try_seconds(self.parameters.within)
+            .and_then(|within| now.checked_add_signed(within))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.list.result.as_mut().unwrap().retain(|hold| {
+            hold.expiry()
+                .is_some_and(|expiry| expiry > now && expiry <= deadline)
+        });
+        Ok(self)
+    }
+
+    /*
+     * True if any hold is about to expire
+     */
+    pub fn found(&self) -> bool {
+        self.list
+            .result
+            .as_ref()
+            .is_some_and(|result| !result.is_empty())
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        self.list.show()
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub user: Option<String>,
+    // Seconds
+    pub within: i64,
+}
@@ -1,17 +1,18 @@
 use crate::autohold::list_parameters::Parameters;
 use crate::autohold::list_struct::AutoHoldResult;
 use crate::config::Config;
+use crate::enums::hold_sort::HoldSort;
+use crate::enums::hold_state::HoldState;
 use crate::enums::output::Output;
-use crate::util::easy::send_receive;
+use crate::tenants::command::Tenants;
+use crate::util::easy::{send_receive_many, try_send_receive};
+use crate::util::window::TimeWindow;
 use anyhow::Result as AnyhowResult;
-use chrono::prelude::DateTime;
-use chrono::FixedOffset;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use comfy_table::presets::UTF8_BORDERS_ONLY;
 use comfy_table::*;
 use log;
 use regex::Regex;
-use std::time::{Duration, UNIX_EPOCH};
 
 #[derive(Debug)]
 pub struct ListAutoHold {
@@ -27,7 +28,14 @@ impl ListAutoHold {
             config,
             parameters: Parameters {
                 user: None,
-                snapped: true,
+                snapped: false,
+                all_tenants: false,
+                owner: None,
+                project: None,
+                job: None,
+                state: HoldState::All,
+                sort: None,
+                window: TimeWindow::default(),
             },
         })
     }
@@ -44,23 +52,104 @@ impl ListAutoHold {
         Ok(self)
     }
 
+    pub fn all_tenants(&mut self, all_tenants: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.all_tenants = all_tenants;
+        Ok(self)
+    }
+
+    pub fn owner(&mut self, owner: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.owner = owner;
+        Ok(self)
+    }
+
+    pub fn project(&mut self, project: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.project = project;
+        Ok(self)
+    }
+
+    pub fn job(&mut self, job: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.job = job;
+        Ok(self)
+    }
+
+    pub fn state(&mut self, state: HoldState) -> AnyhowResult<&mut Self> {
+        self.parameters.state = state;
+        Ok(self)
+    }
+
+    pub fn sort(&mut self, sort: Option<HoldSort>) -> AnyhowResult<&mut Self> {
+        self.parameters.sort = sort;
+        Ok(self)
+    }
+
+    pub fn since(&mut self, since: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.since = since;
+        Ok(self)
+    }
+
+    pub fn until(&mut self, until: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.until = until;
+        Ok(self)
+    }
+
     pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
         log::debug!("{self:#?}");
-        let mut data: Vec<u8> = Vec::new();
-        let url = format!(
-            "https://{}/api/tenant/{}/autohold",
-            self.config.host, self.config.tenant,
-        );
+        let mut output = match self.parameters.all_tenants {
+            true => self.request_all_tenants()?,
+            false => self.request()?,
+        };
 
-        send_receive(&mut data, &url);
+        let patterns = self.config.filters.owner_regexes()?;
+        for autohold in output.iter_mut() {
+            autohold.extract_owner(&patterns);
+        }
 
-        let output: Vec<AutoHoldResult> = serde_json::from_slice(&data)?;
         self.result = Some(output);
         Ok(self)
     }
 
+    fn request(&self) -> AnyhowResult<Vec<AutoHoldResult>> {
+        let mut data: Vec<u8> = Vec::new();
+        let url = self.url(&self.config.tenant);
+
+        try_send_receive(&mut data, &url, &self.config.http)?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /*
+     * Holds of every tenant, the most recently held first. The autohold API
+     * returns every hold of a tenant, so the merged list is not limited
+     * either.
+     */
+    fn request_all_tenants(&self) -> AnyhowResult<Vec<AutoHoldResult>> {
+        let tenants = Tenants::names(&self.config)?;
+        let urls: Vec<String> = tenants.iter().map(|tenant| self.url(tenant)).collect();
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+
+        let mut output: Vec<AutoHoldResult> = vec![];
+        for body in bodies {
+            let autoholds: Vec<AutoHoldResult> = serde_json::from_slice(&body)?;
+            output.extend(autoholds);
+        }
+        output.sort_by_key(|autohold| std::cmp::Reverse(autohold.held_at()));
+        Ok(output)
+    }
+
+    fn url(&self, tenant: &str) -> String {
+        format!(
+            "https://{}/api/tenant/{}/autohold",
+            self.config.host, tenant
+        )
+    }
+
     pub fn filter(&mut self) -> AnyhowResult<&mut Self> {
         let mut tmp: Vec<AutoHoldResult> = vec![];
+        let owner = match &self.parameters.owner {
+            Some(owner) => Some(Regex::new(owner)?),
+            None => None,
+        };
+        let now = Utc::now();
 
         for autohold in self.result.take().unwrap() {
             // User filter
@@ -73,14 +162,55 @@ impl ListAutoHold {
                 }
             }
 
+            // Owner filter
+            if let Some(owner) = &owner {
+                if !owner.is_match(&autohold.owner) {
+                    continue;
+                }
+            }
+
+            // Project filter
+            if let Some(project) = &self.parameters.project {
+                if !autohold.is_project(project) {
+                    continue;
+                }
+            }
+
+            // Job filter
+            if let Some(job) = &self.parameters.job {
+                if &autohold.job != job {
+                    continue;
+                }
+            }
+
+            // Time window filter, the autohold API has no paging
+            if !self.parameters.window.contains(autohold.held_at().as_ref()) {
+                continue;
+            }
+
             // Snapped filter
             if autohold.current_count == 0 && self.parameters.snapped {
                 continue;
             }
 
+            // State filter, holds never fulfilled are active
+            if !self.parameters.state.selects(autohold.state(now)) {
+                continue;
+            }
+
             tmp.push(autohold);
         }
 
+        match self.parameters.sort {
+            Some(HoldSort::Expiry) => {
+                tmp.sort_by_key(|autohold| (autohold.expiry().is_none(), autohold.expiry()))
+            }
+            Some(HoldSort::Count) => {
+                tmp.sort_by_key(|autohold| (autohold.current_count, autohold.max_count))
+            }
+            None => (),
+        }
+
         self.result = Some(tmp);
         Ok(self)
     }
@@ -102,14 +232,19 @@ impl ListAutoHold {
     }
 
     fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut header = vec!["Snapped", "Owner", "Build", "Expire", "Project", "Job"];
+        if self.parameters.all_tenants {
+            header.insert(0, "Tenant");
+        }
+
         let mut table = Table::new();
         table
             .load_preset(UTF8_BORDERS_ONLY)
             .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-            .set_header(vec![
-                "Snapped", "Owner", "Build", "Expire", "Project", "Job",
-            ]);
+            .set_header(header);
 
+        let zone = self.config.zone()?;
+        let now = Utc::now();
         for elem in self.result.as_ref().unwrap() {
             let snapped = match elem.current_count == elem.max_count {
                 true => format!("😁 [{:#?}/{:#?}]", elem.current_count, elem.max_count),
@@ -126,20 +261,9 @@ impl ListAutoHold {
                 }
             };
 
-            let regexp = Regex::new("by (.*)@")?;
-            let cap = regexp.captures(&elem.reason).unwrap();
-
-            let expire = match elem.expired {
-                Some(value) => {
-                    // Creates a new SystemTime from the specified number of whole seconds
-                    let d = UNIX_EPOCH
-                        + Duration::from_secs(value as u64 + elem.node_expiration as u64);
-                    // Create DateTime from SystemTime
-                    let datetime = DateTime::<Utc>::from(d);
-                    // Formats the combined date and time with the specified format string.
-                    let g = datetime.format("%Y-%m-%d %H:%M:%S").to_string();
-
-                    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+            let expire = match elem.expiry() {
+                Some(datetime) => {
+                    let g = zone.format(&datetime);
                     match now > datetime {
                         true => format!("🫥 {}", g),
                         false => format!("😀 {}", g),
@@ -148,14 +272,18 @@ impl ListAutoHold {
                 None => "N/A".to_string(),
             };
 
-            table.add_row(vec![
+            let mut row = vec![
                 Cell::new(snapped),
-                Cell::new(&cap[1]),
+                Cell::new(elem.owner.clone()),
                 Cell::new(builds.join("\n")),
                 Cell::new(expire),
                 Cell::new(elem.project.clone()),
                 Cell::new(elem.job.clone()),
-            ]);
+            ];
+            if self.parameters.all_tenants {
+                row.insert(0, Cell::new(elem.tenant.clone()));
+            }
+            table.add_row(row);
         }
         println!("{table}");
         Ok(())
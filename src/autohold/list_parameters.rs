@@ -1,7 +1,18 @@
+use crate::enums::hold_sort::HoldSort;
+use crate::enums::hold_state::HoldState;
+use crate::util::window::TimeWindow;
 use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Parameters {
     pub user: Option<String>,
     pub snapped: bool,
+    pub all_tenants: bool,
+    pub owner: Option<String>,
+    pub project: Option<String>,
+    pub job: Option<String>,
+    pub state: HoldState,
+    pub sort: Option<HoldSort>,
+    // Filter on when the nodes were held
+    pub window: TimeWindow,
 }
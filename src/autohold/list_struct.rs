@@ -1,4 +1,9 @@
+use crate::enums::hold_state::HoldState;
+use chrono::prelude::DateTime;
+use chrono::Utc;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, UNIX_EPOCH};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Nodes {
@@ -19,4 +24,69 @@ pub struct AutoHoldResult {
     pub node_expiration: usize,
     pub expired: Option<f64>,
     pub nodes: Vec<Nodes>,
+    // Not from Zuul, extracted from the reason
+    #[serde(default)]
+    pub owner: String,
+}
+
+impl AutoHoldResult {
+    /*
+     * Set the requester of the hold from the reason using the first
+     * matching pattern. The first capture group is the owner, or the whole
+     * match if the pattern has no group.
+     */
+    pub fn extract_owner(&mut self, patterns: &[Regex]) {
+        self.owner = patterns
+            .iter()
+            .find_map(|pattern| pattern.captures(&self.reason))
+            .and_then(|cap| cap.get(1).or(cap.get(0)))
+            .map(|owner| owner.as_str().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+    }
+
+    /*
+     * When the nodes were held, None until the hold is fulfilled
+     */
+    pub fn held_at(&self) -> Option<DateTime<Utc>> {
+        self.expired
+            .and_then(|secs| DateTime::from_timestamp_millis((secs * 1000.0) as i64))
+    }
+
+    /*
+     * Zuul returns the canonical project name, e.g.
+     * `review.example.org/org/project`, which `org/project` matches as well
+     */
+    pub fn is_project(&self, project: &str) -> bool {
+        self.project == project
+            || self
+                .project
+                .strip_suffix(project)
+                .is_some_and(|prefix| prefix.ends_with('/'))
+    }
+
+    /*
+     * When the held nodes are deleted, None until the hold is fulfilled
+     */
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        self.expired.map(|value| {
+            // Creates a new SystemTime from the specified number of whole seconds
+            let d = UNIX_EPOCH + Duration::from_secs(value as u64 + self.node_expiration as u64);
+            // Create DateTime from SystemTime
+            DateTime::<Utc>::from(d)
+        })
+    }
+
+    /*
+     * State of the hold at `now`, expiration wins over the counts
+     */
+    pub fn state(&self, now: DateTime<Utc>) -> HoldState {
+        if self.expiry().is_some_and(|expiry| expiry < now) {
+            return HoldState::Expired;
+        }
+        match self.current_count {
+            0 => HoldState::Pending,
+            count if count < self.max_count => HoldState::Partial,
+            _ => HoldState::Complete,
+        }
+    }
 }
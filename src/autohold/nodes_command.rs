@@ -0,0 +1,180 @@
+use crate::autohold::list_command::ListAutoHold;
+use crate::autohold::nodes_parameters::Parameters;
+use crate::builds::builds_struct::BuildsResult;
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::functions::build_node::command::{_Node, BuildNode, LoggedBuild};
+use crate::util::easy::send_receive_many;
+use anyhow::Result as AnyhowResult;
+use chrono::prelude::DateTime;
+use chrono::Utc;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HeldNode {
+    pub hold_id: String,
+    pub owner: String,
+    pub job: String,
+    pub expiry: Option<DateTime<Utc>>,
+    pub node: _Node,
+}
+
+#[derive(Debug)]
+pub struct HeldNodes {
+    pub result: Vec<HeldNode>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl HeldNodes {
+    pub fn new(config: Config) -> AnyhowResult<HeldNodes> {
+        Ok(Self {
+            result: vec![],
+            config,
+            parameters: Parameters {
+                user: None,
+                force: false,
+            },
+        })
+    }
+
+    pub fn user(&mut self, user: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.user = user;
+        Ok(self)
+    }
+
+    pub fn force(&mut self, force: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.force = force;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut holds = ListAutoHold::new(self.config.clone())?;
+        holds
+            .user(self.parameters.user.clone())?
+            .snapped(true)?
+            .runner()?
+            .filter()?;
+
+        let holds = holds.result.take().unwrap();
+        let mut uuids: Vec<String> = holds
+            .iter()
+            .flat_map(|hold| hold.nodes.iter().map(|held| held.build.clone()))
+            .collect();
+        uuids.sort();
+        uuids.dedup();
+
+        // Build records of every held build, fetched concurrently
+        let urls: Vec<String> = uuids
+            .iter()
+            .map(|uuid| {
+                format!(
+                    "https://{}/api/tenant/{}/builds?uuid={uuid}",
+                    self.config.host, self.config.tenant
+                )
+            })
+            .collect();
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+
+        let mut builds: Vec<LoggedBuild> = vec![];
+        for (uuid, body) in uuids.iter().zip(bodies) {
+            let records: Vec<BuildsResult> = serde_json::from_slice(&body)?;
+            // The record may have been pruned, the other holds are still listed
+            let Some(record) = records.into_iter().next() else {
+                eprintln!("Skipping held build {uuid}: no build record");
+                continue;
+            };
+            let (Some(log_url), Some(end_time)) = (record.log_url, record.end_time) else {
+                eprintln!("Skipping held build {uuid}: no logs");
+                continue;
+            };
+            builds.push(LoggedBuild {
+                uuid: uuid.clone(),
+                job_name: record.job_name,
+                log_url,
+                end_time,
+            });
+        }
+
+        // Inventories are cached per build, see build_node
+        let mut build_node = BuildNode::new(self.config.clone())?;
+        build_node
+            .force(self.parameters.force)?
+            .runner_logged(&builds)?;
+
+        let mut nodes: HashMap<String, Vec<_Node>> = HashMap::new();
+        for node in build_node.result.drain(..) {
+            nodes.entry(node.build.clone()).or_default().push(node);
+        }
+
+        for hold in holds.iter() {
+            for held in hold.nodes.iter() {
+                for node in nodes.get(&held.build).into_iter().flatten() {
+                    self.result.push(HeldNode {
+                        hold_id: hold.id.clone(),
+                        owner: hold.owner.clone(),
+                        job: hold.job.clone(),
+                        expiry: hold.expiry(),
+                        node: node.clone(),
+                    });
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result)?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec![
+                "Owner", "Node", "IP addr", "Label", "Expire", "Build", "Job",
+            ]);
+
+        let zone = self.config.zone()?;
+        let now = Utc::now();
+        for held in self.result.iter() {
+            let expire = match held.expiry {
+                Some(datetime) => {
+                    let g = zone.format(&datetime);
+                    match now > datetime {
+                        true => format!("🫥 {}", g),
+                        false => format!("😀 {}", g),
+                    }
+                }
+                None => "N/A".to_string(),
+            };
+
+            table.add_row(vec![
+                Cell::new(held.owner.clone()),
+                Cell::new(held.node.name.clone()),
+                Cell::new(held.node.ip.as_deref().unwrap_or("N/A")),
+                Cell::new(held.node.label.clone()),
+                Cell::new(expire),
+                Cell::new(held.node.build.clone()),
+                Cell::new(held.job.clone()),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
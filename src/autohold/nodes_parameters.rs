@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub user: Option<String>,
+    pub force: bool,
+}
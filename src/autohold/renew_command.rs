@@ -0,0 +1,151 @@
+use crate::autohold::list_struct::AutoHoldResult;
+use crate::autohold::renew_parameters::Parameters;
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::util::easy::{send_action, send_receive};
+use crate::util::helpers;
+use anyhow::Result as AnyhowResult;
+use chrono::TimeDelta;
+use log;
+use serde_json::json;
+
+#[derive(Debug)]
+pub struct RenewAutoHold {
+    pub result: Option<serde_json::Value>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl RenewAutoHold {
+    pub fn new(config: Config) -> AnyhowResult<RenewAutoHold> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                id: String::new(),
+                expiration: None,
+                delete_old: false,
+                yes: false,
+            },
+        })
+    }
+
+    pub fn id(&mut self, id: String) -> AnyhowResult<&mut Self> {
+        self.parameters.id = id;
+        Ok(self)
+    }
+
+    pub fn expiration(&mut self, expiration: Option<TimeDelta>) -> AnyhowResult<&mut Self> {
+        self.parameters.expiration = expiration.map(|value| value.num_seconds());
+        Ok(self)
+    }
+
+    pub fn delete_old(&mut self, delete_old: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.delete_old = delete_old;
+        Ok(self)
+    }
+
+    pub fn yes(&mut self, yes: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.yes = yes;
+        Ok(self)
+    }
+
+    /*
+     * Zuul has no update API, so the hold is created again with the same
+     * settings. The old one is only deleted on request as deleting a hold
+     * releases its held nodes.
+     */
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let token = self.config.token()?.to_string();
+        let base = format!(
+            "https://{}/api/tenant/{}",
+            self.config.host, self.config.tenant
+        );
+
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!("{base}/autohold/{}", self.parameters.id);
+        send_receive(&mut data, &url, &self.config.http);
+        let hold: AutoHoldResult = serde_json::from_slice(&data)?;
+
+        if self.parameters.delete_old && !hold.nodes.is_empty() && !self.parameters.yes {
+            let question = format!(
+                "Hold {} has held nodes that are released when it is deleted, continue?",
+                hold.id
+            );
+            if !helpers::confirm(&question)? {
+                anyhow::bail!("Aborted, hold {} is unchanged", hold.id);
+            }
+        }
+
+        let expiration = self
+            .parameters
+            .expiration
+            .unwrap_or(hold.node_expiration as i64);
+        let body = json!({
+            "job": hold.job,
+            "change": null,
+            "ref": hold.ref_filter,
+            "reason": hold.reason,
+            "count": hold.max_count,
+            "node_hold_expiration": expiration,
+        });
+
+        let mut created: Vec<u8> = Vec::new();
+        let url = format!("{base}/project/{}/autohold", hold.project);
+        send_action(
+            &mut created,
+            "POST",
+            &url,
+            Some(&body),
+            &self.config.http,
+            &token,
+        )?;
+
+        if self.parameters.delete_old {
+            let mut deleted: Vec<u8> = Vec::new();
+            let url = format!("{base}/autohold/{}", hold.id);
+            send_action(
+                &mut deleted,
+                "DELETE",
+                &url,
+                None,
+                &self.config.http,
+                &token,
+            )?;
+        }
+
+        self.result = Some(json!({
+            "renewed": hold.id,
+            "deleted": self.parameters.delete_old,
+            "created": serde_json::from_slice::<serde_json::Value>(&created).ok(),
+            "node_hold_expiration": expiration,
+        }));
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let result = self.result.as_ref().unwrap();
+        println!(
+            "Renewed hold {}, node expiration {}s",
+            self.parameters.id, result["node_hold_expiration"]
+        );
+        if self.parameters.delete_old {
+            println!("Deleted hold {}", self.parameters.id);
+        }
+        Ok(())
+    }
+}
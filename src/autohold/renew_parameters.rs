@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub id: String,
+    // Seconds, None keeps the current node expiration
+    pub expiration: Option<i64>,
+    // Delete the renewed hold, releasing its held nodes
+    pub delete_old: bool,
+    pub yes: bool,
+}
@@ -1,13 +1,15 @@
 use crate::util::helpers;
+use crate::util::timestamp;
 use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Artifacts {
-    name: String,
-    url: String,
-    metadata: Option<serde_json::Value>,
+    pub name: String,
+    pub url: String,
+    pub metadata: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,9 +36,11 @@ pub struct BuildsResult {
     pub artifacts: Vec<Artifacts>,
     pub buildset: BuildSet,
     pub duration: Option<f64>,
-    pub end_time: Option<String>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub end_time: Option<DateTime<Utc>>,
     pub event_id: String,
-    pub event_timestamp: String,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub event_timestamp: Option<DateTime<Utc>>,
     #[serde(rename = "final")]
     pub _final: Option<bool>,
     pub held: Option<bool>,
@@ -47,15 +51,27 @@ pub struct BuildsResult {
     pub provides: Vec<String>,
     pub uuid: String,
     pub result: Option<String>,
-    pub start_time: String,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub start_time: Option<DateTime<Utc>>,
     pub voting: bool,
     pub error_detail: Option<String>,
     #[serde(rename = "ref")]
     pub _ref: Ref,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
     #[serde(flatten)]
     pub other: serde_json::Value,
 }
 
+impl BuildsResult {
+    /*
+     * When the build started, the event time while it is queued
+     */
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        self.start_time.or(self.event_timestamp)
+    }
+}
+
 #[derive(Debug)]
 pub struct Target {
     pub dir: PathBuf,
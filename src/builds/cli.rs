@@ -1,8 +1,16 @@
-use clap::Args;
+use crate::util::change_ref::ChangeRef;
+use crate::util::duration::parse_duration;
+use crate::util::timestamp;
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::{Args, Subcommand};
 
 #[derive(Debug, Args, PartialEq, Clone)]
 #[group(required = true)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Builds {
+    #[command(subcommand)]
+    pub command: Option<BuildsCommand>,
+
     /// Ask for job name
     #[arg(
         long,
@@ -23,9 +31,9 @@ pub struct Builds {
     )]
     pub uuid: Option<String>,
 
-    /// Ask for change
-    #[arg(long, short, group = "_change")]
-    pub change: Option<String>,
+    /// Ask for change: number, change,patchset or a review URL
+    #[arg(long, short, group = "_change", value_parser = ChangeRef::parse)]
+    pub change: Option<ChangeRef>,
 
     /// Ask for Patchset requires --change option
     #[arg(long, short, requires("_change"))]
@@ -38,4 +46,38 @@ pub struct Builds {
     /// Verbose output (user)
     #[arg(long, short, default_value_t = false)]
     pub verbose: bool,
+
+    /// Query every tenant
+    #[arg(long, default_value_t = false)]
+    pub all_tenants: bool,
+
+    /// Only after a duration ago or a date, e.g. 2d or 2026-10-01
+    #[arg(long, conflicts_with = "uuid", value_parser = timestamp::parse_bound)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only before a duration ago or a date, e.g. 12h or 2026-10-02
+    #[arg(long, conflicts_with = "uuid", value_parser = timestamp::parse_bound)]
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Subcommand, PartialEq, Clone)]
+pub enum BuildsCommand {
+    /// Compare two builds, e.g. of the same job passing then failing
+    Diff {
+        /// Uuid of the first build
+        #[arg(required = true)]
+        uuid_a: String,
+
+        /// Uuid of the second build
+        #[arg(required = true)]
+        uuid_b: String,
+
+        /// Hide tasks with the same status and a smaller duration delta
+        #[arg(long, short, default_value = "10s", value_parser = parse_duration)]
+        threshold: TimeDelta,
+
+        /// Force a new request
+        #[arg(long, short, default_value_t = false)]
+        force: bool,
+    },
 }
@@ -2,8 +2,14 @@ use crate::builds::builds_struct::{BuildsResult, Target};
 use crate::builds::parameters::Parameters;
 use crate::config::Config;
 use crate::enums::output::Output;
-use crate::util::easy::send_receive;
+use crate::tenants::command::Tenants;
+use crate::util::change_ref::ChangeRef;
+use crate::util::duration::format_seconds;
+use crate::util::easy::{send_receive_many, try_send_receive};
+use crate::util::timestamp;
+use crate::util::window::TimeWindow;
 use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Utc};
 use comfy_table::presets::UTF8_BORDERS_ONLY;
 use comfy_table::*;
 use log;
@@ -37,9 +43,12 @@ impl Builds {
                 job_name: None,
                 change: None,
                 patchset: None,
+                project: None,
                 uuid: None,
                 force: false,
                 verbose: false,
+                all_tenants: false,
+                window: TimeWindow::default(),
             },
         })
     }
@@ -52,11 +61,13 @@ impl Builds {
         Ok(self)
     }
 
-    pub fn change(&mut self, change: Option<String>) -> AnyhowResult<&mut Self> {
-        if change.is_some() {
+    pub fn change(&mut self, change: Option<ChangeRef>) -> AnyhowResult<&mut Self> {
+        if let Some(change) = change {
             self.command = Command::Change;
+            self.parameters.change = Some(change.change);
+            self.parameters.patchset = change.patchset;
+            self.parameters.project = change.project;
         }
-        self.parameters.change = change;
         Ok(self)
     }
 
@@ -68,8 +79,11 @@ impl Builds {
         Ok(self)
     }
 
+    // Overrides the patchset of the change
     pub fn patchset(&mut self, patchset: Option<String>) -> AnyhowResult<&mut Self> {
-        self.parameters.patchset = patchset;
+        if patchset.is_some() {
+            self.parameters.patchset = patchset;
+        }
         Ok(self)
     }
 
@@ -83,14 +97,41 @@ impl Builds {
         Ok(self)
     }
 
+    pub fn all_tenants(&mut self, all_tenants: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.all_tenants = all_tenants;
+        Ok(self)
+    }
+
+    pub fn since(&mut self, since: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.since = since;
+        Ok(self)
+    }
+
+    pub fn until(&mut self, until: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.until = until;
+        Ok(self)
+    }
+
     pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
         log::debug!("{self:#?}");
 
+        if self.parameters.window.is_set() && self.command != Command::Uuid {
+            let result = self.runner_window()?;
+            self.result = Some(result);
+            return Ok(self);
+        }
+
+        if self.parameters.all_tenants {
+            let result = self.runner_all_tenants()?;
+            self.result = Some(result);
+            return Ok(self);
+        }
+
         let result = match &self.command {
             Command::Uuid => self.runner_uuid()?,
             Command::Change => self.runner_change()?,
             Command::JobName => self.runner_job_name()?,
-            _ => panic!("Not implemented"),
+            Command::NotSet => self.runner_latest()?,
         };
 
         self.result = Some(result);
@@ -125,7 +166,7 @@ impl Builds {
     fn update_cache(&mut self, data: &Vec<u8>, target: std::path::PathBuf) -> AnyhowResult<()> {
         if !data.is_empty() {
             log::debug!("Update cache: {target:#?}");
-            std::fs::create_dir(&self.target.as_ref().unwrap().dir)?;
+            std::fs::create_dir_all(&self.target.as_ref().unwrap().dir)?;
             let mut file = std::fs::File::create(target)?;
             file.write_all(data.as_slice())?;
         }
@@ -142,37 +183,129 @@ impl Builds {
         self.request(&mut data)
     }
 
+    /*
+     * Latest builds of the tenant, without filter
+     */
+    fn runner_latest(&mut self) -> AnyhowResult<Vec<BuildsResult>> {
+        let mut data: Vec<u8> = Vec::new();
+        self.request(&mut data)
+    }
+
+    /*
+     * Run the query against every tenant, results bypass the cache. The
+     * builds are merged newest first and limited as a single tenant query.
+     */
+    fn runner_all_tenants(&mut self) -> AnyhowResult<Vec<BuildsResult>> {
+        let tenants = Tenants::names(&self.config)?;
+        let urls: Vec<String> = tenants.iter().map(|tenant| self.url(tenant)).collect();
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+
+        let mut result: Vec<BuildsResult> = vec![];
+        for (tenant, body) in tenants.iter().zip(bodies) {
+            let mut builds: Vec<BuildsResult> = serde_json::from_slice(&body)?;
+            for build in builds.iter_mut() {
+                build.tenant = Some(tenant.clone());
+            }
+            result.extend(builds);
+        }
+
+        result.sort_by_key(|build| std::cmp::Reverse(build.time()));
+        if self.limited() {
+            result.truncate(self.config.limit);
+        }
+        Ok(result)
+    }
+
+    /*
+     * Page through the builds of the time window, the tenants together,
+     * newest first up to the limit of limited queries
+     */
+    fn runner_window(&mut self) -> AnyhowResult<Vec<BuildsResult>> {
+        let tenants = match self.parameters.all_tenants {
+            true => Tenants::names(&self.config)?,
+            false => vec![self.config.tenant.clone()],
+        };
+        let limit = self.limited().then_some(self.config.limit);
+        let urls: Vec<String> = tenants.iter().map(|tenant| self.url(tenant)).collect();
+        let pages = self.parameters.window.fetch(
+            &urls,
+            limit,
+            self.config.parallel,
+            &self.config.http,
+            BuildsResult::time,
+        )?;
+
+        let mut result: Vec<BuildsResult> = vec![];
+        for (tenant, mut builds) in tenants.iter().zip(pages) {
+            if self.parameters.all_tenants {
+                for build in builds.iter_mut() {
+                    build.tenant = Some(tenant.clone());
+                }
+            }
+            result.extend(builds);
+        }
+
+        result.sort_by_key(|build| std::cmp::Reverse(build.time()));
+        if let Some(limit) = limit {
+            result.truncate(limit);
+        }
+        Ok(result)
+    }
+
     fn cache(&self) -> AnyhowResult<Vec<BuildsResult>> {
         log::debug!("Get from cache");
         let fh = std::fs::File::open(&self.target.as_ref().unwrap().uuid)?;
         Ok(serde_json::from_reader(fh)?)
     }
 
-    fn request(&self, data: &mut Vec<u8>) -> AnyhowResult<Vec<BuildsResult>> {
-        log::debug!("New request");
-        let mut url = format!(
-            "https://{}/api/tenant/{}/builds",
-            self.config.host, self.config.tenant
-        );
+    /*
+     * Queries by job name and the latest builds are limited, the builds of a
+     * change or a uuid are all returned
+     */
+    fn limited(&self) -> bool {
+        self.parameters.job_name.is_some() || self.command == Command::NotSet
+    }
+
+    fn url(&self, tenant: &str) -> String {
+        let mut query: Vec<String> = vec![];
+
+        // Paged requests of a time window set their own limit
+        if self.limited() && !self.parameters.window.is_set() {
+            query.push(format!("limit={}", self.config.limit));
+        }
 
         if let Some(job_name) = &self.parameters.job_name {
-            url.push_str(&format!("?limit={}", self.config.limit));
-            url.push_str(&format!("&job_name={job_name}"));
+            query.push(format!("job_name={job_name}"));
         }
 
         if let Some(change) = &self.parameters.change {
-            url.push_str(&format!("?change={change}"));
+            query.push(format!("change={change}"));
         }
 
         if let Some(patchset) = &self.parameters.patchset {
-            url.push_str(&format!("&patchset={patchset}"));
+            query.push(format!("patchset={patchset}"));
+        }
+
+        if let Some(project) = &self.parameters.project {
+            query.push(format!("project={project}"));
         }
 
         if let Some(uuid) = &self.parameters.uuid {
-            url.push_str(&format!("?uuid={uuid}"));
+            query.push(format!("uuid={uuid}"));
+        }
+
+        let url = format!("https://{}/api/tenant/{}/builds", self.config.host, tenant);
+        match query.is_empty() {
+            true => url,
+            false => format!("{url}?{}", query.join("&")),
         }
+    }
 
-        send_receive(data, &url);
+    fn request(&self, data: &mut Vec<u8>) -> AnyhowResult<Vec<BuildsResult>> {
+        log::debug!("New request");
+        let url = self.url(&self.config.tenant);
+
+        try_send_receive(data, &url, &self.config.http)?;
         let _debug: serde_json::Value = serde_json::from_slice(data)?;
         log::debug!("{_debug:#?}");
 
@@ -206,13 +339,12 @@ impl Builds {
         table
             .load_preset(UTF8_BORDERS_ONLY)
             .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-            .set_header(vec!["Result", "Change/Patchset", "URL", "Job"]);
+            .set_header(self.header(vec!["Result", "Started", "Change/Patchset", "URL", "Job"]));
 
+        let now = Utc::now();
         for e in self.result.as_ref().unwrap().iter() {
-            let url = format!(
-                "https://{}/t/{}/build/{}",
-                self.config.host, self.config.tenant, e.uuid,
-            );
+            let tenant = e.tenant.as_ref().unwrap_or(&self.config.tenant);
+            let url = format!("https://{}/t/{}/build/{}", self.config.host, tenant, e.uuid,);
 
             let change_patchset: String = format!("{}/{}", e._ref.change, e._ref.patchset);
 
@@ -221,12 +353,16 @@ impl Builds {
                 None => "N/A",
             };
 
-            table.add_row(vec![
-                Cell::new(result),
-                Cell::new(change_patchset),
-                Cell::new(url),
-                Cell::new(e.job_name.clone()),
-            ]);
+            table.add_row(self.row(
+                tenant,
+                vec![
+                    Cell::new(result),
+                    Cell::new(Self::started(e, &now)),
+                    Cell::new(change_patchset),
+                    Cell::new(url),
+                    Cell::new(e.job_name.clone()),
+                ],
+            ));
         }
         println!("{table}");
         Ok(())
@@ -237,21 +373,21 @@ impl Builds {
         table
             .load_preset(UTF8_BORDERS_ONLY)
             .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-            .set_header(vec![
+            .set_header(self.header(vec![
                 "Result",
+                "Start",
                 "Duration",
                 "Project",
                 "Branch",
                 "Change/Patchset",
                 "URL",
                 "Job",
-            ]);
+            ]));
 
+        let zone = self.config.zone()?;
         for e in self.result.as_ref().unwrap().iter() {
-            let url = format!(
-                "https://{}/t/{}/build/{}",
-                self.config.host, self.config.tenant, e.uuid,
-            );
+            let tenant = e.tenant.as_ref().unwrap_or(&self.config.tenant);
+            let url = format!("https://{}/t/{}/build/{}", self.config.host, tenant, e.uuid,);
 
             let project: String = e._ref.project.to_string();
             let branch: String = e._ref.branch.to_string();
@@ -262,22 +398,49 @@ impl Builds {
                 None => "N/A",
             };
 
-            let duration = match e.duration.as_ref() {
-                Some(value) => &value.to_string(),
-                None => "Not started",
+            let duration = match e.duration {
+                Some(value) => format_seconds(value),
+                None => "Not started".to_string(),
             };
 
-            table.add_row(vec![
-                Cell::new(result),
-                Cell::new(duration),
-                Cell::new(project),
-                Cell::new(branch),
-                Cell::new(change_patchset),
-                Cell::new(url),
-                Cell::new(e.job_name.clone()),
-            ]);
+            table.add_row(self.row(
+                tenant,
+                vec![
+                    Cell::new(result),
+                    Cell::new(zone.format_option(e.start_time.as_ref())),
+                    Cell::new(duration),
+                    Cell::new(project),
+                    Cell::new(branch),
+                    Cell::new(change_patchset),
+                    Cell::new(url),
+                    Cell::new(e.job_name.clone()),
+                ],
+            ));
         }
         println!("{table}");
         Ok(())
     }
+
+    // e.g. `5 min ago`
+    pub fn started(build: &BuildsResult, now: &DateTime<Utc>) -> String {
+        match build.start_time.as_ref() {
+            Some(start_time) => timestamp::relative(start_time, now),
+            None => "Not started".to_string(),
+        }
+    }
+
+    // Prefix the tenant column when querying all tenants
+    fn header<'a>(&self, mut header: Vec<&'a str>) -> Vec<&'a str> {
+        if self.parameters.all_tenants {
+            header.insert(0, "Tenant");
+        }
+        header
+    }
+
+    fn row(&self, tenant: &str, mut row: Vec<Cell>) -> Vec<Cell> {
+        if self.parameters.all_tenants {
+            row.insert(0, Cell::new(tenant));
+        }
+        row
+    }
 }
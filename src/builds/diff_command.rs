@@ -0,0 +1,570 @@
+use crate::builds::builds_struct::{BuildsResult, Target};
+use crate::builds::command::Builds;
+use crate::builds::diff_parameters::Parameters;
+use crate::builds::diff_struct::{DiffResult, FieldDiff, NodeDiff, TaskDiff};
+use crate::builds::job_output_struct::{task_runs, JobOutput, TaskRun};
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::functions::build_node::command::{_Node, BuildNode};
+use crate::util::duration::format_seconds;
+use crate::util::easy::send_receive_optional;
+use crate::util::timestamp::Zone;
+use anyhow::Result as AnyhowResult;
+use chrono::TimeDelta;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use flate2::read::GzDecoder;
+use log;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::io::prelude::*;
+
+#[derive(Debug)]
+pub struct DiffBuilds {
+    pub result: Option<DiffResult>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl DiffBuilds {
+    pub fn new(config: Config) -> AnyhowResult<DiffBuilds> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                uuid_a: String::new(),
+                uuid_b: String::new(),
+                threshold: 0.0,
+                force: false,
+            },
+        })
+    }
+
+    pub fn uuids(&mut self, uuid_a: String, uuid_b: String) -> AnyhowResult<&mut Self> {
+        self.parameters.uuid_a = uuid_a;
+        self.parameters.uuid_b = uuid_b;
+        Ok(self)
+    }
+
+    pub fn threshold(&mut self, threshold: TimeDelta) -> AnyhowResult<&mut Self> {
+        self.parameters.threshold = threshold.num_milliseconds() as f64 / 1000.0;
+        Ok(self)
+    }
+
+    pub fn force(&mut self, force: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.force = force;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let a = self.build(&self.parameters.uuid_a)?;
+        let b = self.build(&self.parameters.uuid_b)?;
+
+        let mut result = DiffResult {
+            fields: Self::fields(&a, &b, &self.config.zone()?),
+            ..Default::default()
+        };
+
+        let nodes_a = self.nodes(&a)?;
+        let nodes_b = self.nodes(&b)?;
+        result.nodes = Self::nodes_diff(nodes_a.as_ref(), nodes_b.as_ref());
+        result.vars = Self::vars_diff(nodes_a.as_ref(), nodes_b.as_ref())?;
+
+        if let (Some(tasks_a), Some(tasks_b)) = (self.tasks(&a)?, self.tasks(&b)?) {
+            let mut tasks = Self::tasks_diff(tasks_a, tasks_b);
+            tasks.retain(|task| Self::significant(task, self.parameters.threshold));
+            result.tasks = Some(tasks);
+        }
+
+        self.result = Some(result);
+        Ok(self)
+    }
+
+    fn build(&self, uuid: &str) -> AnyhowResult<BuildsResult> {
+        let mut builds = Builds::new(self.config.clone())?;
+        builds
+            .uuid(Some(uuid.to_string()))?
+            .force(self.parameters.force)?
+            .runner()?;
+        match builds
+            .result
+            .take()
+            .and_then(|result| result.into_iter().next())
+        {
+            Some(build) => Ok(build),
+            None => anyhow::bail!("No build: {uuid}"),
+        }
+    }
+
+    /*
+     * Nodes and inventory of the build, only finished builds with logs
+     * have one. The cache was already refreshed by build() when forced.
+     */
+    fn nodes(&self, build: &BuildsResult) -> AnyhowResult<Option<BuildNode>> {
+        if build.log_url.is_none() || build.end_time.is_none() {
+            return Ok(None);
+        }
+        let mut nodes = BuildNode::new(self.config.clone())?;
+        nodes.build_id(build.uuid.clone())?.runner()?;
+        Ok(Some(nodes))
+    }
+
+    /*
+     * Tasks from job-output.json, cached next to the inventory
+     */
+    fn tasks(&self, build: &BuildsResult) -> AnyhowResult<Option<Vec<TaskRun>>> {
+        let Some(log_url) = build.log_url.as_ref() else {
+            return Ok(None);
+        };
+        let target = Target::new(build.uuid.clone(), self.config.cache.clone());
+        let cache = target.dir.join("job-output.json");
+
+        if !cache.exists() {
+            let mut data: Vec<u8> = vec![];
+            let found = send_receive_optional(
+                &mut data,
+                &format!("{log_url}job-output.json"),
+                &self.config.http,
+            )? || send_receive_optional(
+                &mut data,
+                &format!("{log_url}job-output.json.gz"),
+                &self.config.http,
+            )?;
+            if !found {
+                return Ok(None);
+            }
+
+            // Served as is when the log server does not set the encoding
+            if data.starts_with(&[0x1f, 0x8b]) {
+                let mut store: Vec<u8> = vec![];
+                GzDecoder::new(data.as_slice()).read_to_end(&mut store)?;
+                data = store;
+            }
+            std::fs::create_dir_all(&target.dir)?;
+            std::fs::write(&cache, &data)?;
+        }
+
+        let output: JobOutput = serde_json::from_slice(&std::fs::read(&cache)?)?;
+        Ok(Some(task_runs(&output)))
+    }
+
+    fn fields(a: &BuildsResult, b: &BuildsResult, zone: &Zone) -> Vec<FieldDiff> {
+        let field = |field: &str, get: &dyn Fn(&BuildsResult) -> String| FieldDiff {
+            field: field.to_string(),
+            a: get(a),
+            b: get(b),
+        };
+        let or_na = |value: Option<&String>| value.cloned().unwrap_or_else(|| "N/A".to_string());
+
+        vec![
+            field("Uuid", &|e| e.uuid.clone()),
+            field("Job", &|e| e.job_name.clone()),
+            field("Result", &|e| or_na(e.result.as_ref())),
+            field("Duration", &|e| match e.duration {
+                Some(value) => format_seconds(value),
+                None => "N/A".to_string(),
+            }),
+            field("Start", &|e| zone.format_option(e.start_time.as_ref())),
+            field("Pipeline", &|e| e.pipeline.clone()),
+            field("Nodeset", &|e| e.nodeset.clone()),
+            field("Project", &|e| e._ref.project.clone()),
+            field("Branch", &|e| e._ref.branch.clone()),
+            field("Change/Patchset", &|e| {
+                format!("{}/{}", e._ref.change, e._ref.patchset)
+            }),
+            field("Ref", &|e| e._ref._ref.clone()),
+            field("Newrev", &|e| or_na(e._ref.newrev.as_ref())),
+            field("Voting", &|e| e.voting.to_string()),
+            field("Provides", &|e| e.provides.join("\n")),
+            field("Artifacts", &|e| {
+                e.artifacts
+                    .iter()
+                    .map(|artifact| artifact.name.clone())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }),
+            field("Error", &|e| e.error_detail.clone().unwrap_or_default()),
+        ]
+    }
+
+    fn nodes_diff(a: Option<&BuildNode>, b: Option<&BuildNode>) -> Vec<NodeDiff> {
+        let names: BTreeSet<&String> = a
+            .into_iter()
+            .chain(b)
+            .flat_map(|nodes| nodes.result.iter().map(|node| &node.name))
+            .collect();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let node_a = Self::find_node(a, name);
+                let node_b = Self::find_node(b, name);
+                NodeDiff {
+                    name: name.clone(),
+                    label_a: node_a.map(|node| node.label.clone()),
+                    label_b: node_b.map(|node| node.label.clone()),
+                    provider_a: node_a.and_then(|node| node.nodepool.provider.clone()),
+                    provider_b: node_b.and_then(|node| node.nodepool.provider.clone()),
+                }
+            })
+            .collect()
+    }
+
+    fn find_node<'a>(nodes: Option<&'a BuildNode>, name: &str) -> Option<&'a _Node> {
+        nodes.and_then(|nodes| nodes.result.iter().find(|node| node.name == name))
+    }
+
+    /*
+     * Job variables of the inventory that differ. The zuul variables
+     * describe the build itself and always differ.
+     */
+    fn vars_diff(a: Option<&BuildNode>, b: Option<&BuildNode>) -> AnyhowResult<Vec<FieldDiff>> {
+        let vars = |nodes: Option<&BuildNode>| {
+            nodes
+                .and_then(|nodes| nodes.inventories.first())
+                .map(|inventory| inventory.all.vars.clone())
+                .unwrap_or_default()
+        };
+        let vars_a = vars(a);
+        let vars_b = vars(b);
+
+        let keys: BTreeSet<String> = vars_a
+            .keys()
+            .chain(vars_b.keys())
+            .filter_map(|key| key.as_str().map(String::from))
+            .filter(|key| key != "zuul")
+            .collect();
+
+        let mut diff: Vec<FieldDiff> = vec![];
+        for key in keys {
+            let value = |vars: &serde_yaml::Mapping| -> AnyhowResult<String> {
+                Ok(match vars.get(key.as_str()) {
+                    Some(value) => serde_json::to_string(value)?,
+                    None => "(unset)".to_string(),
+                })
+            };
+            let field = FieldDiff {
+                a: value(&vars_a)?,
+                b: value(&vars_b)?,
+                field: key,
+            };
+            if field.changed() {
+                diff.push(field);
+            }
+        }
+        Ok(diff)
+    }
+
+    /*
+     * Pair the tasks of both builds by playbook and name in run order, a
+     * task run several times is paired by occurrence
+     */
+    fn tasks_diff(a: Vec<TaskRun>, b: Vec<TaskRun>) -> Vec<TaskDiff> {
+        let mut pending: HashMap<(String, String), VecDeque<usize>> = HashMap::new();
+        for (index, task) in b.iter().enumerate() {
+            pending
+                .entry((task.playbook.clone(), task.name.clone()))
+                .or_default()
+                .push_back(index);
+        }
+
+        let mut matched: Vec<bool> = vec![false; b.len()];
+        let mut diff: Vec<TaskDiff> = vec![];
+        for task in a.into_iter() {
+            let other = pending
+                .get_mut(&(task.playbook.clone(), task.name.clone()))
+                .and_then(|indexes| indexes.pop_front());
+            if let Some(index) = other {
+                matched[index] = true;
+            }
+            let other = other.map(|index| &b[index]);
+            diff.push(TaskDiff {
+                playbook: task.playbook,
+                name: task.name,
+                status_a: Some(task.status),
+                status_b: other.map(|task| task.status.clone()),
+                duration_a: task.duration,
+                duration_b: other.and_then(|task| task.duration),
+            });
+        }
+
+        // Tasks only run by the second build
+        for (task, _) in b.into_iter().zip(matched).filter(|(_, matched)| !matched) {
+            diff.push(TaskDiff {
+                playbook: task.playbook,
+                name: task.name,
+                status_a: None,
+                status_b: Some(task.status),
+                duration_a: None,
+                duration_b: task.duration,
+            });
+        }
+        diff
+    }
+
+    /*
+     * A task is shown when its status changed, it ran in one build only or
+     * its duration moved by at least the threshold
+     */
+    fn significant(task: &TaskDiff, threshold: f64) -> bool {
+        task.status_a != task.status_b || task.delta().is_none_or(|delta| delta.abs() >= threshold)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn new_table(header: Vec<&str>) -> Table {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(header);
+        table
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let result = self.result.as_ref().unwrap();
+        let na = |value: &Option<String>| value.clone().unwrap_or_else(|| "N/A".to_string());
+        let seconds = |value: Option<f64>| match value {
+            Some(value) => format!("{value:.1}s"),
+            None => "N/A".to_string(),
+        };
+
+        // Changed fields are marked with a *
+        let mut table = Self::new_table(vec!["Build", "A", "B"]);
+        for field in result.fields.iter() {
+            let name = match field.changed() {
+                true => format!("* {}", field.field),
+                false => format!("  {}", field.field),
+            };
+            table.add_row(vec![name, field.a.clone(), field.b.clone()]);
+        }
+        println!("{table}");
+
+        if !result.nodes.is_empty() {
+            let mut table = Self::new_table(vec![
+                "Node",
+                "Label A",
+                "Label B",
+                "Provider A",
+                "Provider B",
+            ]);
+            for node in result.nodes.iter() {
+                table.add_row(vec![
+                    node.name.clone(),
+                    na(&node.label_a),
+                    na(&node.label_b),
+                    na(&node.provider_a),
+                    na(&node.provider_b),
+                ]);
+            }
+            println!("{table}");
+        }
+
+        if !result.vars.is_empty() {
+            let mut table = Self::new_table(vec!["Var", "A", "B"]);
+            for var in result.vars.iter() {
+                table.add_row(vec![var.field.clone(), var.a.clone(), var.b.clone()]);
+            }
+            println!("{table}");
+        }
+
+        match &result.tasks {
+            None => println!("No job-output.json, tasks not compared"),
+            Some(tasks) if tasks.is_empty() => println!("No task changed"),
+            Some(tasks) => {
+                let mut table = Self::new_table(vec![
+                    "Playbook",
+                    "Task",
+                    "A",
+                    "B",
+                    "Duration A",
+                    "Duration B",
+                    "Delta",
+                ]);
+                for task in tasks.iter() {
+                    let delta = match task.delta() {
+                        Some(delta) => format!("{delta:+.1}s"),
+                        None => "N/A".to_string(),
+                    };
+                    table.add_row(vec![
+                        task.playbook.clone(),
+                        task.name.clone(),
+                        na(&task.status_a),
+                        na(&task.status_b),
+                        seconds(task.duration_a),
+                        seconds(task.duration_b),
+                        delta,
+                    ]);
+                }
+                println!("{table}");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::build_node::inventory_struct::Inventory;
+
+    fn run(playbook: &str, name: &str, status: &str, duration: f64) -> TaskRun {
+        TaskRun {
+            playbook: playbook.to_string(),
+            name: name.to_string(),
+            status: status.to_string(),
+            duration: Some(duration),
+        }
+    }
+
+    fn key(task: &TaskDiff) -> (&str, Option<&str>, Option<&str>) {
+        (
+            task.name.as_str(),
+            task.status_a.as_deref(),
+            task.status_b.as_deref(),
+        )
+    }
+
+    #[test]
+    fn tasks_paired_by_occurrence() {
+        let a = vec![
+            run("pre.yaml", "setup", "OK", 1.0),
+            run("run.yaml", "retry", "FAILED", 2.0),
+            run("run.yaml", "retry", "OK", 3.0),
+        ];
+        let b = vec![
+            run("run.yaml", "retry", "OK", 4.0),
+            run("pre.yaml", "setup", "OK", 1.5),
+            run("run.yaml", "retry", "CHANGED", 5.0),
+        ];
+        let diff = DiffBuilds::tasks_diff(a, b);
+        assert_eq!(diff.len(), 3);
+        assert_eq!(key(&diff[0]), ("setup", Some("OK"), Some("OK")));
+        assert_eq!(diff[0].delta(), Some(0.5));
+        // First run of A with the first run of B, second with second
+        assert_eq!(key(&diff[1]), ("retry", Some("FAILED"), Some("OK")));
+        assert_eq!(diff[1].duration_b, Some(4.0));
+        assert_eq!(key(&diff[2]), ("retry", Some("OK"), Some("CHANGED")));
+        assert_eq!(diff[2].duration_b, Some(5.0));
+    }
+
+    #[test]
+    fn tasks_same_name_other_playbook() {
+        let a = vec![run("pre.yaml", "setup", "OK", 1.0)];
+        let b = vec![run("post.yaml", "setup", "OK", 1.0)];
+        let diff = DiffBuilds::tasks_diff(a, b);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff[0].playbook, "pre.yaml");
+        assert_eq!(key(&diff[0]), ("setup", Some("OK"), None));
+        assert_eq!(diff[1].playbook, "post.yaml");
+        assert_eq!(key(&diff[1]), ("setup", None, Some("OK")));
+    }
+
+    #[test]
+    fn tasks_only_in_b() {
+        let a = vec![run("run.yaml", "test", "OK", 10.0)];
+        let b = vec![
+            run("run.yaml", "test", "OK", 10.0),
+            run("run.yaml", "test", "OK", 11.0),
+            run("run.yaml", "collect", "OK", 2.0),
+        ];
+        let diff = DiffBuilds::tasks_diff(a, b);
+        assert_eq!(diff.len(), 3);
+        assert_eq!(key(&diff[0]), ("test", Some("OK"), Some("OK")));
+        assert_eq!(key(&diff[1]), ("test", None, Some("OK")));
+        assert_eq!(diff[1].duration_a, None);
+        assert_eq!(diff[1].duration_b, Some(11.0));
+        assert_eq!(key(&diff[2]), ("collect", None, Some("OK")));
+        assert_eq!(diff[2].delta(), None);
+    }
+
+    #[test]
+    fn tasks_threshold_and_status() {
+        let a = vec![
+            run("run.yaml", "fast", "OK", 1.0),
+            run("run.yaml", "slow", "OK", 1.0),
+            run("run.yaml", "broken", "OK", 1.0),
+            run("run.yaml", "removed", "OK", 0.1),
+        ];
+        let b = vec![
+            run("run.yaml", "fast", "OK", 1.5),
+            run("run.yaml", "slow", "OK", 3.0),
+            run("run.yaml", "broken", "FAILED", 1.0),
+        ];
+        let mut diff = DiffBuilds::tasks_diff(a, b);
+        diff.retain(|task| DiffBuilds::significant(task, 2.0));
+        let names: Vec<&str> = diff.iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, ["slow", "broken", "removed"]);
+
+        // Without a threshold every paired task is shown
+        let same = DiffBuilds::tasks_diff(
+            vec![run("run.yaml", "same", "OK", 1.0)],
+            vec![run("run.yaml", "same", "OK", 1.0)],
+        );
+        assert!(DiffBuilds::significant(&same[0], 0.0));
+        assert!(!DiffBuilds::significant(&same[0], 0.5));
+    }
+
+    fn nodes(inventory: &str) -> BuildNode {
+        let mut nodes = BuildNode::new(Config::default()).unwrap();
+        nodes.inventories.push(Inventory::parse(inventory).unwrap());
+        nodes
+    }
+
+    #[test]
+    fn vars_without_zuul() {
+        let a = nodes(
+            "
+all:
+  hosts: {}
+  vars:
+    zuul:
+      build: aaa
+    tox_env: py311
+    same: 1
+    removed: true
+",
+        );
+        let b = nodes(
+            "
+all:
+  hosts: {}
+  vars:
+    zuul:
+      build: bbb
+    tox_env: py312
+    same: 1
+    added: [x]
+",
+        );
+        let diff = DiffBuilds::vars_diff(Some(&a), Some(&b)).unwrap();
+        let rows: Vec<(&str, &str, &str)> = diff
+            .iter()
+            .map(|var| (var.field.as_str(), var.a.as_str(), var.b.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("added", "(unset)", r#"["x"]"#),
+                ("removed", "true", "(unset)"),
+                ("tox_env", r#""py311""#, r#""py312""#),
+            ]
+        );
+
+        // A build without inventory has every var unset
+        let diff = DiffBuilds::vars_diff(Some(&a), None).unwrap();
+        assert_eq!(diff.len(), 3);
+        assert!(diff.iter().all(|var| var.b == "(unset)"));
+    }
+}
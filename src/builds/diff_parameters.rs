@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub uuid_a: String,
+    pub uuid_b: String,
+    // Seconds
+    pub threshold: f64,
+    pub force: bool,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldDiff {
+    pub field: String,
+    pub a: String,
+    pub b: String,
+}
+
+impl FieldDiff {
+    pub fn changed(&self) -> bool {
+        self.a != self.b
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodeDiff {
+    pub name: String,
+    pub label_a: Option<String>,
+    pub label_b: Option<String>,
+    pub provider_a: Option<String>,
+    pub provider_b: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDiff {
+    pub playbook: String,
+    pub name: String,
+    pub status_a: Option<String>,
+    pub status_b: Option<String>,
+    pub duration_a: Option<f64>,
+    pub duration_b: Option<f64>,
+}
+
+impl TaskDiff {
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.duration_b? - self.duration_a?)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DiffResult {
+    pub fields: Vec<FieldDiff>,
+    pub nodes: Vec<NodeDiff>,
+    pub vars: Vec<FieldDiff>,
+    // None when a build has no job-output.json
+    pub tasks: Option<Vec<TaskDiff>>,
+}
@@ -0,0 +1,115 @@
+use crate::util::timestamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Duration {
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub start: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl Duration {
+    /*
+     * Seconds between start and end, None while running
+     */
+    pub fn seconds(&self) -> Option<f64> {
+        Some((self.end? - self.start?).num_milliseconds() as f64 / 1000.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskInfo {
+    pub name: String,
+    pub duration: Option<Duration>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Task {
+    pub task: TaskInfo,
+    // Result of the task per host
+    #[serde(default)]
+    pub hosts: BTreeMap<String, serde_json::Value>,
+}
+
+impl Task {
+    pub fn status(&self) -> String {
+        let any = |key: &str| self.hosts.values().any(|host| host[key] == true);
+        let status = if any("failed") || any("unreachable") {
+            "failed"
+        } else if !self.hosts.is_empty() && self.hosts.values().all(|host| host["skipped"] == true)
+        {
+            "skipped"
+        } else if any("changed") {
+            "changed"
+        } else {
+            "ok"
+        };
+        status.to_string()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlayInfo {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Play {
+    pub play: PlayInfo,
+    #[serde(default)]
+    pub tasks: Vec<Task>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Playbook {
+    pub phase: Option<String>,
+    pub playbook: String,
+    #[serde(default)]
+    pub plays: Vec<Play>,
+}
+
+impl Playbook {
+    /*
+     * Phase and file name, the full path contains the checkout location
+     */
+    pub fn short(&self) -> String {
+        let name = self.playbook.rsplit('/').next().unwrap_or(&self.playbook);
+        match &self.phase {
+            Some(phase) => format!("{phase} {name}"),
+            None => name.to_string(),
+        }
+    }
+}
+
+pub type JobOutput = Vec<Playbook>;
+
+/*
+ * A task as run in the build, flattened from playbooks and plays
+ */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskRun {
+    pub playbook: String,
+    pub name: String,
+    pub status: String,
+    pub duration: Option<f64>,
+}
+
+pub fn task_runs(output: &JobOutput) -> Vec<TaskRun> {
+    let mut runs: Vec<TaskRun> = vec![];
+    for playbook in output.iter() {
+        for play in playbook.plays.iter() {
+            for task in play.tasks.iter() {
+                runs.push(TaskRun {
+                    playbook: playbook.short(),
+                    name: task.task.name.clone(),
+                    status: task.status(),
+                    duration: task.task.duration.as_ref().and_then(|d| d.seconds()),
+                });
+            }
+        }
+    }
+    runs
+}
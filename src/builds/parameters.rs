@@ -1,3 +1,4 @@
+use crate::util::window::TimeWindow;
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -5,7 +6,11 @@ pub struct Parameters {
     pub job_name: Option<String>,
     pub change: Option<String>,
     pub patchset: Option<String>,
+    // Project of the change when given as URL
+    pub project: Option<String>,
     pub uuid: Option<String>,
     pub force: bool,
     pub verbose: bool,
+    pub all_tenants: bool,
+    pub window: TimeWindow,
 }
@@ -1,3 +1,5 @@
+use crate::util::timestamp;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -24,8 +26,48 @@ pub struct BuildSetsResult {
     pub message: Option<String>,
     pub pipeline: String,
     pub event_id: String,
-    pub event_timestamp: Option<String>,
-    pub first_build_start_time: Option<String>,
-    pub last_build_end_time: Option<String>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub event_timestamp: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub first_build_start_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub last_build_end_time: Option<DateTime<Utc>>,
     pub refs: Vec<BuildSetRefs>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+impl BuildSetsResult {
+    /*
+     * Seconds from the first build start to the last build end
+     */
+    pub fn duration(&self) -> Option<f64> {
+        let start = self.first_build_start_time?;
+        let end = self.last_build_end_time?;
+        Some((end - start).num_milliseconds() as f64 / 1000.0)
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildSetBuild {
+    pub uuid: String,
+    pub job_name: String,
+    pub result: Option<String>,
+    pub log_url: Option<String>,
+    pub duration: Option<f64>,
+    #[serde(default, deserialize_with = "timestamp::deserialize_option")]
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(rename = "final")]
+    pub _final: Option<bool>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildSetDetail {
+    pub uuid: String,
+    pub result: Option<String>,
+    pub pipeline: String,
+    #[serde(default)]
+    pub builds: Vec<BuildSetBuild>,
 }
@@ -1,6 +1,8 @@
 use crate::enums::bsresult::BSResults;
+use crate::util::change_ref::ChangeRef;
+use crate::util::timestamp;
+use chrono::{DateTime, Utc};
 use clap::{Args, Parser};
-use serde_json::Number;
 
 #[derive(Debug, Parser, PartialEq)]
 pub struct BuildSets {
@@ -8,6 +10,18 @@ pub struct BuildSets {
     #[arg(long, short)]
     pub result: Option<BSResults>,
 
+    /// Query every tenant
+    #[arg(long, default_value_t = false)]
+    pub all_tenants: bool,
+
+    /// Only after a duration ago or a date, e.g. 2d or 2026-10-01
+    #[arg(long, value_parser = timestamp::parse_bound)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only before a duration ago or a date, e.g. 12h or 2026-10-02
+    #[arg(long, value_parser = timestamp::parse_bound)]
+    pub until: Option<DateTime<Utc>>,
+
     #[clap(flatten)]
     pub group: OptionsGroup,
 }
@@ -19,9 +33,9 @@ pub struct OptionsGroup {
     #[arg(long, short)]
     pub project: Option<String>,
 
-    /// Ask for change
-    #[arg(long, short)]
-    pub change: Option<Number>,
+    /// Ask for change: number, change,patchset or a review URL
+    #[arg(long, short, value_parser = ChangeRef::parse)]
+    pub change: Option<ChangeRef>,
 
     /// Ask for uuid
     #[arg(long, short)]
@@ -1,15 +1,20 @@
-use crate::buildsets::bs_struct::BuildSetsResult;
+use crate::buildsets::bs_struct::{BuildSetDetail, BuildSetsResult};
 use crate::buildsets::parameters::Parameters;
 use crate::config::Config;
 use crate::enums::bsresult::BSResults;
 use crate::enums::output::Output;
-use crate::util::easy::send_receive;
+use crate::tenants::command::Tenants;
+use crate::util::change_ref::ChangeRef;
+use crate::util::duration::format_seconds;
+use crate::util::easy::{send_receive_many, try_send_receive};
+use crate::util::timestamp;
+use crate::util::window::TimeWindow;
 use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Utc};
 use comfy_table::presets::UTF8_BORDERS_ONLY;
 use comfy_table::*;
 use log;
 use serde::{Deserialize, Serialize};
-use serde_json::Number;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BuildSets {
@@ -28,6 +33,8 @@ impl BuildSets {
                 result: None,
                 change: None,
                 uuid: None,
+                all_tenants: false,
+                window: TimeWindow::default(),
             },
         })
     }
@@ -42,7 +49,7 @@ impl BuildSets {
         Ok(self)
     }
 
-    pub fn change(&mut self, change: Option<Number>) -> AnyhowResult<&mut Self> {
+    pub fn change(&mut self, change: Option<ChangeRef>) -> AnyhowResult<&mut Self> {
         self.parameters.change = change;
         Ok(self)
     }
@@ -52,35 +59,148 @@ impl BuildSets {
         Ok(self)
     }
 
+    pub fn all_tenants(&mut self, all_tenants: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.all_tenants = all_tenants;
+        Ok(self)
+    }
+
+    pub fn since(&mut self, since: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.since = since;
+        Ok(self)
+    }
+
+    pub fn until(&mut self, until: Option<DateTime<Utc>>) -> AnyhowResult<&mut Self> {
+        self.parameters.window.until = until;
+        Ok(self)
+    }
+
+    /*
+     * A buildset with its builds
+     */
+    pub fn detail(config: &Config, uuid: &str) -> AnyhowResult<BuildSetDetail> {
+        let url = format!(
+            "https://{}/api/tenant/{}/buildset/{}",
+            config.host, config.tenant, uuid
+        );
+        let mut data: Vec<u8> = Vec::new();
+        try_send_receive(&mut data, &url, &config.http)?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
     pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
         log::debug!("{self:#?}");
+        if self.parameters.window.is_set() {
+            return self.runner_window();
+        }
+        if self.parameters.all_tenants {
+            return self.runner_all_tenants();
+        }
+
         let mut data: Vec<u8> = Vec::new();
-        let mut url = format!(
-            "https://{}/api/tenant/{}/buildsets?limit={}",
-            self.config.host, self.config.tenant, self.config.limit
-        );
+        let url = self.url(&self.config.tenant);
+        try_send_receive(&mut data, &url, &self.config.http)?;
+
+        let output: Vec<BuildSetsResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    /*
+     * Run the query against every tenant, the buildsets are merged newest
+     * first and limited as a single tenant query
+     */
+    fn runner_all_tenants(&mut self) -> AnyhowResult<&mut Self> {
+        let tenants = Tenants::names(&self.config)?;
+        let urls: Vec<String> = tenants.iter().map(|tenant| self.url(tenant)).collect();
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+
+        let mut output: Vec<BuildSetsResult> = vec![];
+        for (tenant, body) in tenants.iter().zip(bodies) {
+            let mut buildsets: Vec<BuildSetsResult> = serde_json::from_slice(&body)?;
+            for buildset in buildsets.iter_mut() {
+                buildset.tenant = Some(tenant.clone());
+            }
+            output.extend(buildsets);
+        }
+
+        output.sort_by_key(|buildset| std::cmp::Reverse(buildset.event_timestamp));
+        output.truncate(self.config.limit);
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    /*
+     * Page through the buildsets of the time window, the tenants together,
+     * newest first up to the limit
+     */
+    fn runner_window(&mut self) -> AnyhowResult<&mut Self> {
+        let tenants = match self.parameters.all_tenants {
+            true => Tenants::names(&self.config)?,
+            false => vec![self.config.tenant.clone()],
+        };
+        let urls: Vec<String> = tenants.iter().map(|tenant| self.url(tenant)).collect();
+        let pages = self.parameters.window.fetch(
+            &urls,
+            Some(self.config.limit),
+            self.config.parallel,
+            &self.config.http,
+            |buildset: &BuildSetsResult| buildset.event_timestamp,
+        )?;
+
+        let mut output: Vec<BuildSetsResult> = vec![];
+        for (tenant, mut buildsets) in tenants.iter().zip(pages) {
+            if self.parameters.all_tenants {
+                for buildset in buildsets.iter_mut() {
+                    buildset.tenant = Some(tenant.clone());
+                }
+            }
+            output.extend(buildsets);
+        }
+
+        output.sort_by_key(|buildset| std::cmp::Reverse(buildset.event_timestamp));
+        output.truncate(self.config.limit);
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    fn url(&self, tenant: &str) -> String {
+        let mut query: Vec<String> = vec![];
+
+        // Paged requests of a time window set their own limit
+        if !self.parameters.window.is_set() {
+            query.push(format!("limit={}", self.config.limit));
+        }
 
         if let Some(result) = self.parameters.result.as_ref() {
-            url.push_str(&format!("&result={result}"));
+            query.push(format!("result={result}"));
         }
 
         if let Some(uuid) = self.parameters.uuid.as_ref() {
-            url.push_str(&format!("&uuid={uuid}"));
+            query.push(format!("uuid={uuid}"));
         }
 
         if let Some(project) = self.parameters.project.as_ref() {
-            url.push_str(&format!("&project={project}"));
+            query.push(format!("project={project}"));
         }
 
         if let Some(change) = self.parameters.change.as_ref() {
-            url.push_str(&format!("&change={change}"));
+            query.push(format!("change={}", change.change));
+            if let Some(patchset) = change.patchset.as_ref() {
+                query.push(format!("patchset={patchset}"));
+            }
+            if let Some(project) = change.project.as_ref() {
+                query.push(format!("project={project}"));
+            }
         }
 
-        send_receive(&mut data, &url);
-
-        let output: Vec<BuildSetsResult> = serde_json::from_slice(&data)?;
-        self.result = Some(output);
-        Ok(self)
+        let url = format!(
+            "https://{}/api/tenant/{}/buildsets",
+            self.config.host, tenant
+        );
+        match query.is_empty() {
+            true => url,
+            false => format!("{url}?{}", query.join("&")),
+        }
     }
 
     pub fn show(&mut self) -> AnyhowResult<()> {
@@ -103,18 +223,26 @@ impl BuildSets {
         match self.result.as_ref() {
             None => println!("Nothing found"),
             Some(result) => {
+                let mut header = vec![
+                    "Result",
+                    "Pipeline",
+                    "Project",
+                    "Branch",
+                    "Change/Patchset",
+                    "Started",
+                    "Duration",
+                    "URL",
+                ];
+                if self.parameters.all_tenants {
+                    header.insert(0, "Tenant");
+                }
+
                 let mut table = Table::new();
                 table
                     .load_preset(UTF8_BORDERS_ONLY)
                     .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-                    .set_header(vec![
-                        "Result",
-                        "Pipeline",
-                        "Project",
-                        "Branch",
-                        "Change/Patchset",
-                        "URL",
-                    ]);
+                    .set_header(header);
+                let now = Utc::now();
                 for res in result.iter() {
                     let change_patchset = format!(
                         "{}/{}",
@@ -122,18 +250,33 @@ impl BuildSets {
                         res.refs[0].patchset.as_ref().unwrap_or(&"?".to_string())
                     );
 
+                    let tenant = res.tenant.as_ref().unwrap_or(&self.config.tenant);
                     let url = format!(
                         "https://{}/t/{}/buildset/{}",
-                        self.config.host, self.config.tenant, res.uuid
+                        self.config.host, tenant, res.uuid
                     );
-                    table.add_row(vec![
+                    let started = match res.first_build_start_time.as_ref() {
+                        Some(start) => timestamp::relative(start, &now),
+                        None => "Not started".to_string(),
+                    };
+                    let duration = match res.duration() {
+                        Some(duration) => format_seconds(duration),
+                        None => "N/A".to_string(),
+                    };
+                    let mut row = vec![
                         Cell::new(res.result.as_ref().unwrap_or(&"N/A".to_string())),
                         Cell::new(res.pipeline.clone()),
                         Cell::new(res.refs[0].project.clone()),
                         Cell::new(res.refs[0].branch.clone()),
                         Cell::new(change_patchset),
+                        Cell::new(started),
+                        Cell::new(duration),
                         Cell::new(url),
-                    ]);
+                    ];
+                    if self.parameters.all_tenants {
+                        row.insert(0, Cell::new(tenant));
+                    }
+                    table.add_row(row);
                 }
 
                 println!("{table}")
@@ -1,11 +1,14 @@
 use crate::enums::bsresult::BSResults;
+use crate::util::change_ref::ChangeRef;
+use crate::util::window::TimeWindow;
 use serde::{Deserialize, Serialize};
-use serde_json::Number;
 
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub struct Parameters {
     pub project: Option<String>,
     pub result: Option<BSResults>,
-    pub change: Option<Number>,
+    pub change: Option<ChangeRef>,
     pub uuid: Option<String>,
+    pub all_tenants: bool,
+    pub window: TimeWindow,
 }
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildSummary {
+    pub uuid: String,
+    pub job_name: String,
+    pub result: Option<String>,
+    pub duration: Option<f64>,
+    // False for builds Zuul retried, e.g. with result RETRY
+    #[serde(rename = "final")]
+    pub _final: bool,
+}
+
+impl BuildSummary {
+    /*
+     * Retried, skipped and unfinished builds are not failures
+     */
+    pub fn failed(&self) -> bool {
+        self._final
+            && self
+                .result
+                .as_deref()
+                .is_some_and(|result| !matches!(result, "SUCCESS" | "SKIPPED"))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildSetSummary {
+    pub uuid: String,
+    pub result: Option<String>,
+    pub builds: Vec<BuildSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelineSummary {
+    pub pipeline: String,
+    // Oldest first
+    pub buildsets: Vec<BuildSetSummary>,
+}
+
+impl PipelineSummary {
+    pub fn latest(&self) -> Option<&BuildSetSummary> {
+        self.buildsets.last()
+    }
+
+    /*
+     * Every run of the same patchset after the first one
+     */
+    pub fn rechecks(&self) -> usize {
+        self.buildsets.len().saturating_sub(1)
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.buildsets
+            .iter()
+            .flat_map(|buildset| buildset.builds.iter())
+            .filter_map(|build| build.duration)
+            .sum()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PatchsetSummary {
+    pub patchset: String,
+    pub pipelines: Vec<PipelineSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Regression {
+    pub job_name: String,
+    pub pipeline: String,
+    pub result: Option<String>,
+    // Latest earlier patchset the job passed on
+    pub passed_patchset: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ChangeSummary {
+    pub change: String,
+    pub patchset: Option<String>,
+    // Oldest first
+    pub patchsets: Vec<PatchsetSummary>,
+    pub rechecks: usize,
+    pub duration: f64,
+    pub regressions: Vec<Regression>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(result: Option<&str>, _final: bool) -> BuildSummary {
+        BuildSummary {
+            uuid: "uuid".to_string(),
+            job_name: "tox".to_string(),
+            result: result.map(|result| result.to_string()),
+            duration: None,
+            _final,
+        }
+    }
+
+    #[test]
+    fn failed() {
+        assert!(build(Some("FAILURE"), true).failed());
+        assert!(build(Some("POST_FAILURE"), true).failed());
+        assert!(!build(Some("SUCCESS"), true).failed());
+        assert!(!build(Some("SKIPPED"), true).failed());
+        // Retried, or still running
+        assert!(!build(Some("RETRY"), false).failed());
+        assert!(!build(Some("FAILURE"), false).failed());
+        assert!(!build(None, true).failed());
+    }
+}
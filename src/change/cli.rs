@@ -0,0 +1,13 @@
+use crate::util::change_ref::ChangeRef;
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Change {
+    /// Change: number, change,patchset or a review URL
+    #[arg(required = true, value_parser = ChangeRef::parse)]
+    pub change: ChangeRef,
+
+    /// Patchset to summarize, default the latest
+    #[arg(long, short)]
+    pub patchset: Option<String>,
+}
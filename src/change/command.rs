@@ -0,0 +1,327 @@
+use crate::buildsets::bs_struct::{BuildSetDetail, BuildSetsResult};
+use crate::change::change_struct::{
+    BuildSetSummary, BuildSummary, ChangeSummary, PatchsetSummary, PipelineSummary, Regression,
+};
+use crate::change::parameters::Parameters;
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::util::change_ref::ChangeRef;
+use crate::util::duration::format_seconds;
+use crate::util::easy::{send_receive_many, try_send_receive};
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct Change {
+    pub result: Option<ChangeSummary>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Change {
+    pub fn new(config: Config) -> AnyhowResult<Change> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                change: String::new(),
+                patchset: None,
+                project: None,
+            },
+        })
+    }
+
+    pub fn change(&mut self, change: ChangeRef) -> AnyhowResult<&mut Self> {
+        self.parameters.change = change.change;
+        self.parameters.patchset = change.patchset;
+        self.parameters.project = change.project;
+        Ok(self)
+    }
+
+    // Overrides the patchset of the change
+    pub fn patchset(&mut self, patchset: Option<String>) -> AnyhowResult<&mut Self> {
+        if patchset.is_some() {
+            self.parameters.patchset = patchset;
+        }
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let base = format!(
+            "https://{}/api/tenant/{}",
+            self.config.host, self.config.tenant
+        );
+        let mut url = format!(
+            "{base}/buildsets?change={}&limit={}",
+            self.parameters.change, self.config.limit
+        );
+        if let Some(project) = &self.parameters.project {
+            url.push_str(&format!("&project={project}"));
+        }
+        let mut data: Vec<u8> = Vec::new();
+        try_send_receive(&mut data, &url, &self.config.http)?;
+        let mut buildsets: Vec<BuildSetsResult> = serde_json::from_slice(&data)?;
+
+        if buildsets.is_empty() {
+            anyhow::bail!("No buildsets for change: {}", self.parameters.change);
+        }
+        if buildsets.len() >= self.config.limit {
+            eprintln!(
+                "Showing the latest {} buildsets of change {}, raise --limit for older ones",
+                buildsets.len(),
+                self.parameters.change
+            );
+        }
+
+        // Every build of each buildset, retries included
+        let urls: Vec<String> = buildsets
+            .iter()
+            .map(|buildset| format!("{base}/buildset/{}", buildset.uuid))
+            .collect();
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+        let details: Vec<BuildSetDetail> = bodies
+            .iter()
+            .map(|body| serde_json::from_slice(body))
+            .collect::<Result<_, _>>()?;
+
+        // Zuul returns the newest first
+        buildsets.sort_by_key(|buildset| buildset._id.as_u64());
+
+        let mut summary = ChangeSummary {
+            change: self.parameters.change.clone(),
+            patchsets: Self::group(&buildsets, &details),
+            ..Default::default()
+        };
+
+        let current = match &self.parameters.patchset {
+            Some(patchset) => summary
+                .patchsets
+                .iter()
+                .position(|summary| &summary.patchset == patchset),
+            None => summary.patchsets.len().checked_sub(1),
+        };
+        let Some(current) = current else {
+            anyhow::bail!(
+                "No buildsets for patchset: {}",
+                self.parameters.patchset.as_deref().unwrap_or("N/A")
+            );
+        };
+
+        summary.patchset = Some(summary.patchsets[current].patchset.clone());
+        summary.regressions = Self::regressions(&summary.patchsets, current);
+
+        // Totals of the whole change unless a patchset was asked for
+        let patchsets = match self.parameters.patchset.is_some() {
+            true => &summary.patchsets[current..=current],
+            false => &summary.patchsets[..],
+        };
+        for pipeline in patchsets.iter().flat_map(|p| p.pipelines.iter()) {
+            summary.rechecks += pipeline.rechecks();
+            summary.duration += pipeline.duration();
+        }
+
+        self.result = Some(summary);
+        Ok(self)
+    }
+
+    /*
+     * Group the buildsets by patchset then pipeline, in order of appearance
+     */
+    fn group(buildsets: &[BuildSetsResult], details: &[BuildSetDetail]) -> Vec<PatchsetSummary> {
+        let mut patchsets: Vec<PatchsetSummary> = vec![];
+        for buildset in buildsets.iter() {
+            let patchset = buildset
+                .refs
+                .first()
+                .and_then(|r| r.patchset.clone())
+                .unwrap_or_else(|| "N/A".to_string());
+
+            let index = match patchsets.iter().position(|p| p.patchset == patchset) {
+                Some(index) => index,
+                None => {
+                    patchsets.push(PatchsetSummary {
+                        patchset,
+                        pipelines: vec![],
+                    });
+                    patchsets.len() - 1
+                }
+            };
+            let pipelines = &mut patchsets[index].pipelines;
+
+            let index = match pipelines
+                .iter()
+                .position(|p| p.pipeline == buildset.pipeline)
+            {
+                Some(index) => index,
+                None => {
+                    pipelines.push(PipelineSummary {
+                        pipeline: buildset.pipeline.clone(),
+                        buildsets: vec![],
+                    });
+                    pipelines.len() - 1
+                }
+            };
+
+            let mut members: Vec<BuildSummary> = details
+                .iter()
+                .filter(|detail| detail.uuid == buildset.uuid)
+                .flat_map(|detail| detail.builds.iter())
+                .map(|build| BuildSummary {
+                    uuid: build.uuid.clone(),
+                    job_name: build.job_name.clone(),
+                    result: build.result.clone(),
+                    duration: build.duration,
+                    _final: build._final.unwrap_or(true),
+                })
+                .collect();
+            members.sort_by(|a, b| a.job_name.cmp(&b.job_name));
+
+            pipelines[index].buildsets.push(BuildSetSummary {
+                uuid: buildset.uuid.clone(),
+                result: buildset.result.clone(),
+                builds: members,
+            });
+        }
+        patchsets
+    }
+
+    /*
+     * Jobs failing in the latest run of the current patchset which passed
+     * on an earlier patchset
+     */
+    fn regressions(patchsets: &[PatchsetSummary], current: usize) -> Vec<Regression> {
+        let mut regressions: Vec<Regression> = vec![];
+        for pipeline in patchsets[current].pipelines.iter() {
+            let Some(latest) = pipeline.latest() else {
+                continue;
+            };
+            for build in latest.builds.iter().filter(|build| build.failed()) {
+                let passed = patchsets[..current].iter().rev().find(|patchset| {
+                    patchset
+                        .pipelines
+                        .iter()
+                        .flat_map(|p| p.buildsets.iter())
+                        .flat_map(|buildset| buildset.builds.iter())
+                        .any(|b| {
+                            b.job_name == build.job_name && b.result.as_deref() == Some("SUCCESS")
+                        })
+                });
+                if let Some(passed) = passed {
+                    regressions.push(Regression {
+                        job_name: build.job_name.clone(),
+                        pipeline: pipeline.pipeline.clone(),
+                        result: build.result.clone(),
+                        passed_patchset: passed.patchset.clone(),
+                    });
+                }
+            }
+        }
+        regressions
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let summary = self.result.as_ref().unwrap();
+        let current = summary.patchset.clone().unwrap_or_default();
+
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec![
+                "Patchset",
+                "Pipeline",
+                "Runs",
+                "Result",
+                "Failed jobs",
+                "CI time",
+            ]);
+
+        // All patchsets unless one was asked for
+        for patchset in summary
+            .patchsets
+            .iter()
+            .filter(|patchset| self.parameters.patchset.is_none() || patchset.patchset == current)
+        {
+            for pipeline in patchset.pipelines.iter() {
+                let latest = pipeline.latest();
+                let failed: Vec<String> = latest
+                    .map(|buildset| {
+                        buildset
+                            .builds
+                            .iter()
+                            .filter(|build| build.failed())
+                            .map(|build| build.job_name.clone())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                table.add_row(vec![
+                    Cell::new(patchset.patchset.clone()),
+                    Cell::new(pipeline.pipeline.clone()),
+                    Cell::new(pipeline.buildsets.len()),
+                    Cell::new(
+                        latest
+                            .and_then(|buildset| buildset.result.clone())
+                            .unwrap_or_else(|| "N/A".to_string()),
+                    ),
+                    Cell::new(failed.join("\n")),
+                    Cell::new(format_seconds(pipeline.duration())),
+                ]);
+            }
+        }
+        println!("{table}");
+
+        let verdicts: Vec<String> = summary
+            .patchsets
+            .iter()
+            .filter(|patchset| patchset.patchset == current)
+            .flat_map(|patchset| patchset.pipelines.iter())
+            .map(|pipeline| {
+                let result = pipeline
+                    .latest()
+                    .and_then(|buildset| buildset.result.clone())
+                    .unwrap_or_else(|| "N/A".to_string());
+                format!("{} {result}", pipeline.pipeline)
+            })
+            .collect();
+        println!("Patchset {current}: {}", verdicts.join(", "));
+        println!(
+            "Rechecks: {}, total CI time: {}",
+            summary.rechecks,
+            format_seconds(summary.duration)
+        );
+
+        if !summary.regressions.is_empty() {
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_BORDERS_ONLY)
+                .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+                .set_header(vec!["Regression", "Pipeline", "Result", "Passed on"]);
+            for regression in summary.regressions.iter() {
+                table.add_row(vec![
+                    Cell::new(regression.job_name.clone()),
+                    Cell::new(regression.pipeline.clone()),
+                    Cell::new(regression.result.as_deref().unwrap_or("N/A")),
+                    Cell::new(format!("patchset {}", regression.passed_patchset)),
+                ]);
+            }
+            println!("{table}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub change: String,
+    pub patchset: Option<String>,
+    // Project of the change when given as URL
+    pub project: Option<String>,
+}
@@ -1,8 +1,20 @@
 use crate::autohold;
 use crate::builds;
 use crate::buildsets;
+use crate::change;
+use crate::config_errors;
 use crate::enums;
+use crate::freeze_jobs;
 use crate::functions;
+use crate::jobs;
+use crate::manage;
+use crate::nodes;
+use crate::pipelines;
+use crate::projects;
+use crate::semaphores;
+use crate::system;
+use crate::tenants;
+use crate::tui;
 use clap::{Parser, Subcommand};
 use clap_complete::Shell;
 
@@ -25,6 +37,54 @@ pub struct Cli {
     #[arg(global = true, long, default_value_t = 10)]
     pub limit: usize,
 
+    /// Max concurrent requests, overrides configuration
+    #[arg(global = true, long)]
+    pub parallel: Option<usize>,
+
+    /// Connect timeout in seconds, overrides configuration
+    #[arg(global = true, long)]
+    pub connect_timeout: Option<u64>,
+
+    /// Total request timeout in seconds, overrides configuration
+    #[arg(global = true, long)]
+    pub timeout: Option<u64>,
+
+    /// Retries on 5xx and connection errors, overrides configuration
+    #[arg(global = true, long)]
+    pub retries: Option<u32>,
+
+    /// HTTP(S) proxy, overrides configuration
+    #[arg(global = true, long)]
+    pub proxy: Option<String>,
+
+    /// CA bundle path, overrides configuration
+    #[arg(global = true, long)]
+    pub cacert: Option<String>,
+
+    /// Client certificate path, overrides configuration
+    #[arg(global = true, long)]
+    pub cert: Option<String>,
+
+    /// Client certificate key path, overrides configuration
+    #[arg(global = true, long)]
+    pub key: Option<String>,
+
+    /// Auth token for admin actions, overrides configuration
+    #[arg(global = true, long, env = "SOL_AUTH_TOKEN", hide_env_values = true)]
+    pub auth_token: Option<String>,
+
+    /// Skip TLS certificate verification
+    #[arg(global = true, long, default_value_t = false)]
+    pub insecure: bool,
+
+    /// Show times in UTC, overrides configuration
+    #[arg(global = true, long, default_value_t = false, conflicts_with = "local")]
+    pub utc: bool,
+
+    /// Show times in the local timezone, overrides configuration
+    #[arg(global = true, long, default_value_t = false)]
+    pub local: bool,
+
     /// Output format
     #[arg(global = true, long, default_value_t = enums::output::Output::USER)]
     pub output: enums::output::Output,
@@ -43,4 +103,61 @@ pub enum Commands {
 
     /// Functions
     Functions(functions::cli::Functions),
+
+    /// List tenants
+    Tenants(tenants::cli::Tenants),
+
+    /// List projects
+    Projects(projects::cli::Projects),
+
+    /// Project configuration
+    Project(projects::cli::Project),
+
+    /// List jobs
+    Jobs(jobs::cli::Jobs),
+
+    /// Job definition
+    Job(jobs::cli::Job),
+
+    /// List pipelines
+    Pipelines(pipelines::cli::Pipelines),
+
+    /// Change queues of the pipelines
+    Queues(pipelines::cli::Queues),
+
+    /// List nodepool labels
+    Labels(nodes::cli::Labels),
+
+    /// List nodepool nodes
+    Nodes(nodes::cli::Nodes),
+
+    /// List semaphores and their holders
+    Semaphores(semaphores::cli::Semaphores),
+
+    /// Report tenant configuration errors
+    ConfigErrors(config_errors::cli::ConfigErrors),
+
+    /// Preview the jobs frozen for a project, pipeline and branch
+    FreezeJobs(freeze_jobs::cli::FreezeJobs),
+
+    /// Zuul system information
+    Info(system::cli::Info),
+
+    /// Zuul components and their state
+    Components(system::cli::Components),
+
+    /// Enqueue a change or ref in a pipeline, needs an auth token
+    Enqueue(manage::cli::Enqueue),
+
+    /// Dequeue a change or ref from a pipeline, needs an auth token
+    Dequeue(manage::cli::Dequeue),
+
+    /// Move changes to the top of a pipeline, needs an auth token
+    Promote(manage::cli::Promote),
+
+    /// Summary of the builds of a change per patchset and pipeline
+    Change(change::cli::Change),
+
+    /// Browse status, buildsets, builds and holds in the terminal
+    Tui(tui::cli::Tui),
 }
@@ -1,8 +1,10 @@
 use crate::cli_struct::Cli;
 use crate::enums::output::Output;
+use crate::util::timestamp::Zone;
 use anyhow::Result as AnyhowResult;
 use lazy_static::lazy_static;
 use log;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
@@ -30,6 +32,67 @@ lazy_static! {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Filter {
     pub autohold_user: String,
+
+    // Patterns extracting the autohold owner from the reason
+    #[serde(default = "default_autohold_owner")]
+    pub autohold_owner: Vec<String>,
+}
+
+fn default_autohold_owner() -> Vec<String> {
+    vec!["by (.*)@".to_string()]
+}
+
+impl Filter {
+    pub fn owner_regexes(&self) -> AnyhowResult<Vec<Regex>> {
+        Ok(self
+            .autohold_owner
+            .iter()
+            .map(|pattern| Regex::new(pattern))
+            .collect::<Result<Vec<Regex>, _>>()?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Http {
+    // Connect timeout in seconds
+    pub connect_timeout: Option<u64>,
+
+    // Total request timeout in seconds
+    pub timeout: Option<u64>,
+
+    // Retries on 5xx and connection errors
+    pub retries: u32,
+
+    // HTTP(S) proxy
+    pub proxy: Option<String>,
+
+    // CA bundle path
+    pub ca_bundle: Option<String>,
+
+    // Client certificate path
+    pub client_cert: Option<String>,
+
+    // Client certificate key path
+    pub client_key: Option<String>,
+
+    // Skip TLS verification
+    pub insecure: bool,
+}
+
+impl Default for Http {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Some(10),
+            timeout: Some(120),
+            retries: 3,
+            proxy: None,
+            ca_bundle: None,
+            client_cert: None,
+            client_key: None,
+            insecure: false,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,6 +114,26 @@ pub struct Config {
 
     // Filters
     pub filters: Filter,
+
+    // Max concurrent requests
+    #[serde(default = "default_parallel")]
+    pub parallel: usize,
+
+    // HTTP transport
+    #[serde(default)]
+    pub http: Http,
+
+    // Token for the tenant admin endpoints
+    #[serde(default)]
+    pub auth_token: Option<String>,
+
+    // Timezone of the displayed times: utc, local or an IANA name
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+fn default_parallel() -> usize {
+    8
 }
 
 impl Default for Config {
@@ -67,7 +150,12 @@ impl Default for Config {
             limit: 10,
             filters: Filter {
                 autohold_user: "PATTERN".to_string(),
+                autohold_owner: default_autohold_owner(),
             },
+            parallel: default_parallel(),
+            http: Http::default(),
+            auth_token: None,
+            timezone: None,
         }
     }
 }
@@ -87,6 +175,42 @@ impl Config {
         if let Some(tenant) = cli.tenant.as_ref() {
             config.tenant = tenant.clone();
         }
+        if let Some(parallel) = cli.parallel {
+            config.parallel = parallel;
+        }
+        if let Some(connect_timeout) = cli.connect_timeout {
+            config.http.connect_timeout = Some(connect_timeout);
+        }
+        if let Some(timeout) = cli.timeout {
+            config.http.timeout = Some(timeout);
+        }
+        if let Some(retries) = cli.retries {
+            config.http.retries = retries;
+        }
+        if let Some(proxy) = cli.proxy.as_ref() {
+            config.http.proxy = Some(proxy.clone());
+        }
+        if let Some(cacert) = cli.cacert.as_ref() {
+            config.http.ca_bundle = Some(cacert.clone());
+        }
+        if let Some(cert) = cli.cert.as_ref() {
+            config.http.client_cert = Some(cert.clone());
+        }
+        if let Some(key) = cli.key.as_ref() {
+            config.http.client_key = Some(key.clone());
+        }
+        if let Some(auth_token) = cli.auth_token.as_ref() {
+            config.auth_token = Some(auth_token.clone());
+        }
+        if cli.insecure {
+            config.http.insecure = true;
+        }
+        if cli.utc {
+            config.timezone = Some("utc".to_string());
+        }
+        if cli.local {
+            config.timezone = Some("local".to_string());
+        }
         config.limit = cli.limit;
         config.output = cli.output;
         log::debug!("Config after overrides: {:#?}", config);
@@ -95,7 +219,57 @@ impl Config {
         Ok(config)
     }
 
+    /*
+     * Load the configuration only if it exists, never initialize a new one
+     */
+    pub fn try_load(cli: &Cli) -> Option<Config> {
+        match Path::new(&SOL_CONFIG.as_str()).exists() {
+            true => Config::load(cli).ok(),
+            false => None,
+        }
+    }
+
+    /*
+     * The auth token, required by commands acting on Zuul
+     */
+    pub fn token(&self) -> AnyhowResult<&str> {
+        match self.auth_token.as_deref() {
+            Some(token) => Ok(token),
+            None => anyhow::bail!("An auth token is required, set auth_token or --auth-token"),
+        }
+    }
+
+    /*
+     * The timezone to display times in, Zuul's UTC by default
+     */
+    pub fn zone(&self) -> AnyhowResult<Zone> {
+        match self.timezone.as_deref() {
+            Some(timezone) => Zone::parse(timezone),
+            None => Ok(Zone::Utc),
+        }
+    }
+
     fn validate(config: &Config) -> AnyhowResult<()> {
+        if config.parallel == 0 {
+            anyhow::bail!("parallel must be at least 1");
+        }
+
+        config.zone()?;
+
+        config.filters.owner_regexes()?;
+
+        // Validate certificate files exists
+        let files = [
+            &config.http.ca_bundle,
+            &config.http.client_cert,
+            &config.http.client_key,
+        ];
+        for file in files.into_iter().flatten() {
+            if !Path::new(file).exists() {
+                anyhow::bail!("No such file: {file}");
+            }
+        }
+
         // Validate cache directory exists
         match Path::new(config.cache.as_str()).exists() {
             true => (),
@@ -0,0 +1,13 @@
+use crate::enums::severity::Severity;
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct ConfigErrors {
+    /// Only show errors of this project
+    #[arg(long, short)]
+    pub project: Option<String>,
+
+    /// Only show errors of this severity
+    #[arg(long, short)]
+    pub severity: Option<Severity>,
+}
@@ -0,0 +1,143 @@
+use crate::config::Config;
+use crate::config_errors::errors_struct::ConfigErrorsResult;
+use crate::config_errors::parameters::Parameters;
+use crate::enums::output::Output;
+use crate::enums::severity::Severity;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+pub struct ConfigErrors {
+    pub result: Option<Vec<ConfigErrorsResult>>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl ConfigErrors {
+    pub fn new(config: Config) -> AnyhowResult<ConfigErrors> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                project: None,
+                severity: None,
+            },
+        })
+    }
+
+    pub fn project(&mut self, project: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.project = project;
+        Ok(self)
+    }
+
+    pub fn severity(&mut self, severity: Option<Severity>) -> AnyhowResult<&mut Self> {
+        self.parameters.severity = severity;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/config-errors",
+            self.config.host, self.config.tenant,
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<ConfigErrorsResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn filter(&mut self) -> AnyhowResult<&mut Self> {
+        let parameters = &self.parameters;
+        self.result.as_mut().unwrap().retain(|error| {
+            parameters
+                .project
+                .as_ref()
+                .is_none_or(|project| error.project() == Some(project))
+                && parameters
+                    .severity
+                    .is_none_or(|severity| error.severity == severity)
+        });
+        Ok(self)
+    }
+
+    /*
+     * Used as exit status, computed on the filtered result. Warnings alone do
+     * not break the tenant, unless asked for with --severity.
+     */
+    pub fn found(&self) -> bool {
+        let selected = self.parameters.severity.is_some();
+        self.result.as_ref().is_some_and(|errors| {
+            errors
+                .iter()
+                .any(|e| selected || e.severity == Severity::Error)
+        })
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        // Group by project, branch and file
+        let mut groups: BTreeMap<(String, String, String), Vec<&ConfigErrorsResult>> =
+            BTreeMap::new();
+        for error in self.result.as_ref().unwrap() {
+            let context = error.source_context.clone().unwrap_or_default();
+            let key = (
+                context.project.unwrap_or_else(|| "N/A".to_string()),
+                context.branch.unwrap_or_else(|| "N/A".to_string()),
+                context.path.unwrap_or_else(|| "N/A".to_string()),
+            );
+            groups.entry(key).or_default().push(error);
+        }
+
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec![
+                "Project", "Branch", "File", "Line", "Severity", "Error",
+            ]);
+
+        for ((project, branch, path), errors) in groups.iter() {
+            for (i, error) in errors.iter().enumerate() {
+                let source = match i {
+                    0 => [project.as_str(), branch.as_str(), path.as_str()],
+                    _ => ["", "", ""],
+                };
+                table.add_row(vec![
+                    Cell::new(source[0]),
+                    Cell::new(source[1]),
+                    Cell::new(source[2]),
+                    Cell::new(
+                        error
+                            .line()
+                            .map(|line| line.to_string())
+                            .unwrap_or_default(),
+                    ),
+                    Cell::new(error.severity),
+                    Cell::new(error.error.trim()),
+                ]);
+            }
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::enums::severity::Severity;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+lazy_static! {
+    static ref LINE: Regex = Regex::new(r"\bline (\d+)").unwrap();
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigErrorsResult {
+    pub source_context: Option<SourceContext>,
+    pub error: String,
+    pub short_error: Option<String>,
+    #[serde(default)]
+    pub severity: Severity,
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceContext {
+    pub project: Option<String>,
+    pub branch: Option<String>,
+    pub path: Option<String>,
+    pub start_mark: Option<Mark>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mark {
+    pub line: u64,
+    pub column: Option<u64>,
+}
+
+impl ConfigErrorsResult {
+    pub fn project(&self) -> Option<&str> {
+        self.source_context
+            .as_ref()
+            .and_then(|context| context.project.as_deref())
+    }
+
+    /*
+     * Line of the error in the file. The mark of the source context is 0
+     * based, otherwise fall back to the line reported by the YAML parser in
+     * the error text.
+     */
+    pub fn line(&self) -> Option<u64> {
+        if let Some(mark) = self
+            .source_context
+            .as_ref()
+            .and_then(|context| context.start_mark.as_ref())
+        {
+            return Some(mark.line + 1);
+        }
+        LINE.captures(&self.error)
+            .and_then(|captures| captures[1].parse().ok())
+    }
+}
@@ -0,0 +1,8 @@
+use crate::enums::severity::Severity;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub project: Option<String>,
+    pub severity: Option<Severity>,
+}
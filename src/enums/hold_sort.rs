@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum HoldSort {
+    // Soonest first, holds never fulfilled last
+    Expiry,
+    // Fewest held builds first
+    Count,
+}
+
+impl fmt::Display for HoldSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = match *self {
+            Self::Expiry => "expiry",
+            Self::Count => "count",
+        };
+        write!(f, "{}", x)
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum HoldState {
+    All,
+    // No build held yet
+    Pending,
+    // Some but not all builds held
+    Partial,
+    // All builds held
+    Complete,
+    // Held nodes are past their expiration
+    Expired,
+    // Any state but expired
+    Active,
+}
+
+impl fmt::Display for HoldState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = match *self {
+            Self::All => "all",
+            Self::Pending => "pending",
+            Self::Partial => "partial",
+            Self::Complete => "complete",
+            Self::Expired => "expired",
+            Self::Active => "active",
+        };
+        write!(f, "{}", x)
+    }
+}
+
+impl HoldState {
+    /*
+     * The state of a hold is selected by this filter
+     */
+    pub fn selects(&self, state: HoldState) -> bool {
+        match self {
+            Self::All => true,
+            Self::Active => state != Self::Expired,
+            _ => *self == state,
+        }
+    }
+}
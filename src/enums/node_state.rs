@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum NodeState {
+    Building,
+    Ready,
+    InUse,
+    Used,
+    Hold,
+    Deleting,
+    Failed,
+}
+
+impl fmt::Display for NodeState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl NodeState {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            NodeState::Building => "building",
+            NodeState::Ready => "ready",
+            NodeState::InUse => "in-use",
+            NodeState::Used => "used",
+            NodeState::Hold => "hold",
+            NodeState::Deleting => "deleting",
+            NodeState::Failed => "failed",
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum QueueAction {
+    Enqueue,
+    Dequeue,
+}
+
+impl fmt::Display for QueueAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = match *self {
+            Self::Enqueue => "enqueue",
+            Self::Dequeue => "dequeue",
+        };
+        write!(f, "{}", x)
+    }
+}
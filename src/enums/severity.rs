@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, clap::ValueEnum, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    // Older Zuul versions only report errors
+    #[default]
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = match *self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        };
+        write!(f, "{}", x)
+    }
+}
@@ -0,0 +1,21 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Clone, ValueEnum, Debug, PartialEq, Copy, Eq)]
+pub enum VarsFormat {
+    Tree,
+    Yaml,
+    Json,
+}
+
+impl fmt::Display for VarsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = match *self {
+            Self::Tree => "tree",
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+        };
+        write!(f, "{}", x)
+    }
+}
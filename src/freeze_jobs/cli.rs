@@ -0,0 +1,20 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct FreezeJobs {
+    /// Project name
+    #[arg(long, short = 'P', required = true)]
+    pub project: String,
+
+    /// Pipeline name
+    #[arg(long, short, required = true)]
+    pub pipeline: String,
+
+    /// Branch name
+    #[arg(long, short, required = true)]
+    pub branch: String,
+
+    /// Show the frozen variables and playbooks of this job
+    #[arg(long, short)]
+    pub job: Option<String>,
+}
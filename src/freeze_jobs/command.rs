@@ -0,0 +1,197 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::freeze_jobs::freeze_struct::{FreezeJobsResult, FrozenJob, Playbook};
+use crate::freeze_jobs::parameters::Parameters;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct FreezeJobs {
+    pub result: Option<Vec<FreezeJobsResult>>,
+    pub job: Option<FrozenJob>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl FreezeJobs {
+    pub fn new(config: Config) -> AnyhowResult<FreezeJobs> {
+        Ok(Self {
+            result: None,
+            job: None,
+            config,
+            parameters: Parameters {
+                project: String::new(),
+                pipeline: String::new(),
+                branch: String::new(),
+                job: None,
+            },
+        })
+    }
+
+    pub fn project(&mut self, project: String) -> AnyhowResult<&mut Self> {
+        self.parameters.project = project;
+        Ok(self)
+    }
+
+    pub fn pipeline(&mut self, pipeline: String) -> AnyhowResult<&mut Self> {
+        self.parameters.pipeline = pipeline;
+        Ok(self)
+    }
+
+    pub fn branch(&mut self, branch: String) -> AnyhowResult<&mut Self> {
+        self.parameters.branch = branch;
+        Ok(self)
+    }
+
+    pub fn job(&mut self, job: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.job = job;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let base = format!(
+            "https://{}/api/tenant/{}/pipeline/{}/project/{}/branch/{}",
+            self.config.host,
+            self.config.tenant,
+            self.parameters.pipeline,
+            self.parameters.project,
+            self.parameters.branch,
+        );
+
+        let mut data: Vec<u8> = Vec::new();
+        match &self.parameters.job {
+            Some(job) => {
+                send_receive(
+                    &mut data,
+                    &format!("{base}/freeze-job/{job}"),
+                    &self.config.http,
+                );
+                self.job = Some(serde_json::from_slice(&data)?);
+            }
+            None => {
+                send_receive(&mut data, &format!("{base}/freeze-jobs"), &self.config.http);
+                self.result = Some(serde_json::from_slice(&data)?);
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        match &self.job {
+            Some(job) => println!("{}", serde_json::to_string(job)?),
+            None => println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?),
+        }
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        match self.job.is_some() {
+            true => self._show_user_job()?,
+            false => self._show_user_jobs()?,
+        }
+        Ok(())
+    }
+
+    fn _show_user_jobs(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Job", "Depends on"]);
+
+        for job in self.result.as_ref().unwrap() {
+            let dependencies: Vec<String> = job
+                .dependencies
+                .iter()
+                .map(|dependency| match dependency.soft {
+                    true => format!("{} (soft)", dependency.name),
+                    false => dependency.name.clone(),
+                })
+                .collect();
+            table.add_row(vec![
+                Cell::new(job.name.clone()),
+                Cell::new(dependencies.join("\n")),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+
+    fn _show_user_job(&mut self) -> AnyhowResult<()> {
+        let job = self.job.as_ref().unwrap();
+
+        let nodeset = match &job.nodeset {
+            Some(nodeset) => nodeset["nodes"]
+                .as_array()
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|node| node["label"].as_str())
+                        .collect::<Vec<&str>>()
+                        .join("\n")
+                })
+                .unwrap_or_default(),
+            None => "N/A".to_string(),
+        };
+
+        let timeout = match job.timeout {
+            Some(value) => format!("{value}s"),
+            None => "N/A".to_string(),
+        };
+
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Job", &job.job]);
+        table.add_row(vec![
+            "Ansible",
+            job.ansible_version.as_deref().unwrap_or("N/A"),
+        ]);
+        table.add_row(vec!["Nodes", &nodeset]);
+        table.add_row(vec!["Timeout", &timeout]);
+        table.add_row(vec!["Pre-run", &Self::playbooks(&job.pre_playbooks)]);
+        table.add_row(vec!["Run", &Self::playbooks(&job.playbooks)]);
+        table.add_row(vec!["Post-run", &Self::playbooks(&job.post_playbooks)]);
+        table.add_row(vec![
+            "Cleanup-run",
+            &Self::playbooks(&job.cleanup_playbooks),
+        ]);
+        table.add_row(vec!["Vars", &Self::vars(&job.vars)?]);
+        table.add_row(vec!["Extra vars", &Self::vars(&job.extra_vars)?]);
+        table.add_row(vec!["Host vars", &Self::vars(&job.host_vars)?]);
+        table.add_row(vec!["Group vars", &Self::vars(&job.group_vars)?]);
+        println!("{table}");
+        Ok(())
+    }
+
+    fn playbooks(playbooks: &[Playbook]) -> String {
+        playbooks
+            .iter()
+            .map(|playbook| match playbook.trusted {
+                true => format!("{} (trusted)", playbook.location()),
+                false => playbook.location(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn vars(vars: &serde_json::Value) -> AnyhowResult<String> {
+        Ok(match vars.as_object() {
+            Some(map) if !map.is_empty() => serde_yaml::to_string(vars)?.trim_end().to_string(),
+            _ => String::new(),
+        })
+    }
+}
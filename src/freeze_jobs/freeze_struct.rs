@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    #[serde(default)]
+    pub soft: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FreezeJobsResult {
+    pub name: String,
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Playbook {
+    pub project: String,
+    pub branch: Option<String>,
+    pub path: String,
+    #[serde(default)]
+    pub trusted: bool,
+}
+
+impl Playbook {
+    pub fn location(&self) -> String {
+        match &self.branch {
+            Some(branch) => format!("{}@{}:{}", self.project, branch, self.path),
+            None => format!("{}:{}", self.project, self.path),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrozenJob {
+    pub job: String,
+    pub ansible_version: Option<String>,
+    pub nodeset: Option<serde_json::Value>,
+    pub timeout: Option<u64>,
+    pub post_timeout: Option<u64>,
+    #[serde(default)]
+    pub pre_playbooks: Vec<Playbook>,
+    #[serde(default)]
+    pub playbooks: Vec<Playbook>,
+    #[serde(default)]
+    pub post_playbooks: Vec<Playbook>,
+    #[serde(default)]
+    pub cleanup_playbooks: Vec<Playbook>,
+    #[serde(default)]
+    pub vars: serde_json::Value,
+    #[serde(default)]
+    pub extra_vars: serde_json::Value,
+    #[serde(default)]
+    pub host_vars: serde_json::Value,
+    #[serde(default)]
+    pub group_vars: serde_json::Value,
+    #[serde(flatten)]
+    pub other: serde_json::Value,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub project: String,
+    pub pipeline: String,
+    pub branch: String,
+    pub job: Option<String>,
+}
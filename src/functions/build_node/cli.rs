@@ -3,10 +3,26 @@ use clap::Parser;
 #[derive(Debug, Parser, PartialEq)]
 pub struct BuildNode {
     /// Build ID
-    #[arg(required = true)]
-    pub build_id: String,
+    #[arg(required_unless_present = "buildset", conflicts_with = "buildset")]
+    pub build_id: Option<String>,
+
+    /// List nodes of every build in a buildset
+    #[arg(long, short)]
+    pub buildset: Option<String>,
 
     /// Don't use cache if any
     #[arg(long, short, default_value_t = false)]
     pub force: bool,
+
+    /// Show group membership
+    #[arg(long, short, default_value_t = false)]
+    pub groups: bool,
+
+    /// Show the variables of a host
+    #[arg(long, value_name = "HOST")]
+    pub vars: Option<String>,
+
+    /// Show IPv6 address
+    #[arg(long, default_value_t = false)]
+    pub ipv6: bool,
 }
@@ -1,33 +1,57 @@
 use crate::builds;
 use crate::builds::builds_struct::Target;
+use crate::buildsets::command::BuildSets;
 use crate::config::Config;
 use crate::enums::output::Output;
+use crate::functions::build_node::inventory_struct::{Inventory, Nodepool};
 use crate::functions::build_node::parameters::Parameters;
 use crate::util::diffdatetime_now::{self, DiffDateTimeNow};
-use crate::util::easy::send_receive;
+use crate::util::easy::{send_receive_many_optional, try_send_receive};
 use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Utc};
 use comfy_table::presets::UTF8_BORDERS_ONLY;
 use comfy_table::*;
 use flate2::read::GzDecoder;
 use log;
 use serde::{Deserialize, Serialize};
 use serde_yaml;
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct _Node {
     pub name: String,
-    pub ip: String,
+    pub build: String,
+    pub job: String,
+    pub ip: Option<String>,
+    pub ipv6: Option<String>,
     pub label: String,
+    pub user: Option<String>,
+    pub port: Option<u64>,
+    pub host_keys: Vec<String>,
+    pub groups: Vec<String>,
+    pub nodepool: Nodepool,
+    pub vars: serde_yaml::Mapping,
     pub age: DiffDateTimeNow,
 }
 
+/*
+ * A finished build, its inventory is under the log url
+ */
+#[derive(Debug, Clone)]
+pub struct LoggedBuild {
+    pub uuid: String,
+    pub job_name: String,
+    pub log_url: String,
+    pub end_time: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BuildNode {
     pub result: Vec<_Node>,
+    pub inventories: Vec<Inventory>,
     pub config: Config,
     pub parameters: Parameters,
 }
@@ -36,10 +60,15 @@ impl BuildNode {
     pub fn new(config: Config) -> AnyhowResult<BuildNode> {
         Ok(Self {
             result: vec![],
+            inventories: vec![],
             config,
             parameters: Parameters {
                 build_id: String::new(),
+                buildset: None,
                 force: false,
+                groups: false,
+                vars: None,
+                ipv6: false,
             },
         })
     }
@@ -49,13 +78,37 @@ impl BuildNode {
         Ok(self)
     }
 
+    pub fn buildset(&mut self, buildset: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.buildset = buildset;
+        Ok(self)
+    }
+
     pub fn force(&mut self, force: bool) -> AnyhowResult<&mut Self> {
         self.parameters.force = force;
         Ok(self)
     }
 
+    pub fn groups(&mut self, groups: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.groups = groups;
+        Ok(self)
+    }
+
+    pub fn vars(&mut self, vars: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.vars = vars;
+        Ok(self)
+    }
+
+    pub fn ipv6(&mut self, ipv6: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.ipv6 = ipv6;
+        Ok(self)
+    }
+
     pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
         log::debug!("{self:#?}");
+        if self.parameters.buildset.is_some() {
+            return self.runner_buildset();
+        }
+
         let target = Target::new(self.parameters.build_id.clone(), self.config.cache.clone());
 
         // Get log_url from Build
@@ -64,22 +117,101 @@ impl BuildNode {
         build.force(self.parameters.force)?;
         build.runner()?;
 
-        let log_url = build.result.as_ref().unwrap()[0].log_url.clone().unwrap();
-        let end_time = build.result.as_ref().unwrap()[0].end_time.clone().unwrap();
-        let age = diffdatetime_now::DiffDateTimeNow::new(format!("{}+00:00", end_time.clone()));
+        // Unknown uuid, or a build record pruned from the database
+        let Some(result) = build.result.as_ref().and_then(|result| result.first()) else {
+            anyhow::bail!("No build: {}", self.parameters.build_id);
+        };
+        let (Some(log_url), Some(end_time)) = (result.log_url.clone(), result.end_time) else {
+            anyhow::bail!("Build {} has no logs yet", self.parameters.build_id);
+        };
+        let job_name = result.job_name.clone();
+        let age = diffdatetime_now::DiffDateTimeNow::new(&end_time);
 
         if target.inventory.exists() && self.parameters.force || !target.inventory.exists() {
             self.request(&target.inventory, &log_url)?;
         }
 
+        let inventory = Inventory::parse(&Self::read_inventory(&target.inventory)?)?;
+        let build_id = self.parameters.build_id.clone();
+        self.populate(&inventory, build_id, job_name, age)?;
+        Ok(self)
+    }
+
+    /*
+     * Collect the nodes of every build in a buildset
+     */
+    fn runner_buildset(&mut self) -> AnyhowResult<&mut Self> {
+        let uuid = self.parameters.buildset.clone().unwrap();
+        let buildset = BuildSets::detail(&self.config, &uuid)?;
+
+        // Only finished builds with logs have an inventory
+        let builds: Vec<LoggedBuild> = buildset
+            .builds
+            .iter()
+            .filter_map(|build| {
+                Some(LoggedBuild {
+                    uuid: build.uuid.clone(),
+                    job_name: build.job_name.clone(),
+                    log_url: build.log_url.clone()?,
+                    end_time: build.end_time?,
+                })
+            })
+            .collect();
+        self.runner_logged(&builds)
+    }
+
+    /*
+     * Collect the nodes of finished builds. Inventories missing from the
+     * cache are downloaded concurrently, builds whose logs are gone are
+     * skipped with a warning.
+     */
+    pub fn runner_logged(&mut self, builds: &[LoggedBuild]) -> AnyhowResult<&mut Self> {
+        let targets: Vec<Target> = builds
+            .iter()
+            .map(|build| Target::new(build.uuid.clone(), self.config.cache.clone()))
+            .collect();
+
+        let missing: Vec<(&LoggedBuild, &Target)> = builds
+            .iter()
+            .zip(targets.iter())
+            .filter(|(_, target)| self.parameters.force || !target.inventory.exists())
+            .collect();
+        let urls: Vec<String> = missing
+            .iter()
+            .map(|(build, _)| format!("{}zuul-info/inventory.yaml", build.log_url))
+            .collect();
+
+        let bodies = send_receive_many_optional(&urls, self.config.parallel, &self.config.http)?;
+        let mut skipped: HashSet<&str> = HashSet::new();
+        for ((build, target), body) in missing.iter().zip(bodies) {
+            let Some(body) = body else {
+                eprintln!("Skipping build {}: no inventory in its logs", build.uuid);
+                skipped.insert(&build.uuid);
+                continue;
+            };
+            std::fs::create_dir_all(&target.dir)?;
+            let mut file = File::create(&target.inventory)?;
+            file.write_all(body.as_slice())?;
+        }
+
+        for (build, target) in builds.iter().zip(targets.iter()) {
+            if skipped.contains(build.uuid.as_str()) {
+                continue;
+            }
+            let age = DiffDateTimeNow::new(&build.end_time);
+            let inventory = Inventory::parse(&Self::read_inventory(&target.inventory)?)?;
+            self.populate(&inventory, build.uuid.clone(), build.job_name.clone(), age)?;
+        }
+        Ok(self)
+    }
+
+    fn read_inventory(inventory: &Path) -> AnyhowResult<String> {
         // Decompress file
-        let in_fh = std::fs::File::open(target.inventory)?;
+        let in_fh = std::fs::File::open(inventory)?;
         let mut gz = GzDecoder::new(in_fh);
         let mut store = String::new();
         gz.read_to_string(&mut store)?;
-
-        self.parse_n_populate(&mut store, age)?;
-        Ok(self)
+        Ok(store)
     }
 
     fn request(&mut self, inventory: &PathBuf, log_url: &str) -> AnyhowResult<&mut Self> {
@@ -88,7 +220,7 @@ impl BuildNode {
 
         // Download and write cache
         let mut data: Vec<u8> = vec![];
-        send_receive(&mut data, &url);
+        try_send_receive(&mut data, &url, &self.config.http)?;
         {
             let mut file = File::create(inventory)?;
             file.write_all(data.as_slice())?;
@@ -97,51 +229,46 @@ impl BuildNode {
     }
 
     /*
-     * This function parse the inventory.yaml file, create a Node object and store
-     * on the result.
-     *
-     * TODO: Refactor this function
+     * Create a Node object for every host of the inventory and store on the
+     * result.
      */
-    fn parse_n_populate(
+    fn populate(
         &mut self,
-        store: &mut str,
+        inventory: &Inventory,
+        build: String,
+        job: String,
         age: DiffDateTimeNow,
     ) -> AnyhowResult<&mut Self> {
-        let data: serde_yaml::Value = serde_yaml::from_str(store)?;
-        let hosts = data["all"]["hosts"].to_owned();
-        let map: HashMap<String, serde_yaml::Value> = serde_yaml::from_value(hosts)?;
-        let mut collected: HashMap<String, String> = HashMap::new();
-
-        for (host, value) in map.iter() {
-            let m2: HashMap<String, serde_yaml::Value> = serde_yaml::from_value(value.to_owned())?;
-            for (attr, v2) in m2.iter() {
-                if attr == "ansible_host" {
-                    collected.insert("ip".to_string(), v2.as_str().unwrap().to_string());
-                }
-                if attr == "nodepool" {
-                    let m3: HashMap<String, serde_yaml::Value> =
-                        serde_yaml::from_value(v2.to_owned())?;
-                    for (attr2, v3) in m3.iter() {
-                        if attr2 == "label" {
-                            collected.insert("label".to_string(), v3.as_str().unwrap().to_string());
-                        }
-                    }
-                }
-                if collected.len() == 2 {
-                    self.result.push(_Node {
-                        name: host.to_string(),
-                        ip: collected.get("ip").unwrap().clone(),
-                        label: collected.get("label").unwrap().clone(),
-                        age: age.clone(),
-                    });
-                    break;
-                }
-            }
+        for (name, host) in inventory.all.hosts.iter() {
+            self.result.push(_Node {
+                name: name.clone(),
+                build: build.clone(),
+                job: job.clone(),
+                ip: host.ansible_host.clone(),
+                ipv6: host.ipv6().cloned(),
+                label: host
+                    .nodepool
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| "N/A".to_string()),
+                user: host.ansible_user.clone(),
+                port: host.ansible_port,
+                host_keys: host.host_keys.clone(),
+                groups: inventory.groups(name),
+                nodepool: host.nodepool.clone(),
+                vars: inventory.host_vars(name).cloned().unwrap_or_default(),
+                age: age.clone(),
+            });
         }
+        self.inventories.push(inventory.clone());
         Ok(self)
     }
 
     pub fn show(&mut self) -> AnyhowResult<()> {
+        if self.parameters.vars.is_some() {
+            return self._show_vars();
+        }
+
         match self.config.output {
             Output::JSON => self._show_json()?,
             Output::USER => self._show_user()?,
@@ -149,6 +276,19 @@ impl BuildNode {
         Ok(())
     }
 
+    fn _show_vars(&mut self) -> AnyhowResult<()> {
+        let name = self.parameters.vars.as_ref().unwrap();
+        let Some(node) = self.result.iter().find(|node| &node.name == name) else {
+            anyhow::bail!("No host: {name} in inventory");
+        };
+
+        match self.config.output {
+            Output::JSON => println!("{}", serde_json::to_string(&node.vars)?),
+            Output::USER => print!("{}", serde_yaml::to_string(&node.vars)?),
+        }
+        Ok(())
+    }
+
     fn _show_json(&mut self) -> AnyhowResult<()> {
         for node in self.result.iter() {
             println!("{}", serde_json::to_string(node).unwrap());
@@ -157,19 +297,47 @@ impl BuildNode {
     }
 
     fn _show_user(&mut self) -> AnyhowResult<()> {
+        let with_job = self.parameters.buildset.is_some();
+        let mut header = vec!["Node", "IP addr"];
+        if self.parameters.ipv6 {
+            header.push("IPv6 addr");
+        }
+        header.extend(["Label", "Provider", "Region"]);
+        if self.parameters.groups {
+            header.push("Groups");
+        }
+        header.push("Age");
+        if with_job {
+            header.insert(0, "Job");
+        }
+
         let mut table = Table::new();
         table
             .load_preset(UTF8_BORDERS_ONLY)
             .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-            .set_header(vec!["Node", "IP addr", "Label", "Age"]);
+            .set_header(header);
 
         for node in self.result.iter() {
-            table.add_row(vec![
+            let mut row = vec![
                 Cell::new(node.name.clone()),
-                Cell::new(node.ip.clone()),
+                Cell::new(node.ip.as_deref().unwrap_or("N/A")),
+            ];
+            if self.parameters.ipv6 {
+                row.push(Cell::new(node.ipv6.as_deref().unwrap_or("N/A")));
+            }
+            row.extend([
                 Cell::new(node.label.clone()),
-                Cell::new(node.age.clone()),
+                Cell::new(node.nodepool.provider.as_deref().unwrap_or("N/A")),
+                Cell::new(node.nodepool.region.as_deref().unwrap_or("N/A")),
             ]);
+            if self.parameters.groups {
+                row.push(Cell::new(node.groups.join("\n")));
+            }
+            row.push(Cell::new(node.age.clone()));
+            if with_job {
+                row.insert(0, Cell::new(node.job.clone()));
+            }
+            table.add_row(row);
         }
         println!("{table}");
         Ok(())
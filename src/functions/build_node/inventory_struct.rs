@@ -0,0 +1,269 @@
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/*
+ * Nodepool drivers don't agree on scalar types, e.g. external_id can be a
+ * number, so accept any scalar and keep it as a string
+ */
+fn lenient_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<Value> = Option::deserialize(deserializer)?;
+    Ok(match value {
+        Some(Value::String(value)) => Some(value),
+        Some(Value::Number(value)) => Some(value.to_string()),
+        Some(Value::Bool(value)) => Some(value.to_string()),
+        _ => None,
+    })
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Nodepool {
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub label: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub provider: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub region: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub cloud: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub az: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub interface_ip: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub public_ipv4: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub private_ipv4: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub public_ipv6: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub private_ipv6: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub external_id: Option<String>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Host {
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub ansible_host: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub ansible_user: Option<String>,
+    pub ansible_port: Option<u64>,
+    #[serde(default)]
+    pub host_keys: Vec<String>,
+    #[serde(default)]
+    pub nodepool: Nodepool,
+    // Every other host variable
+    #[serde(flatten)]
+    pub vars: BTreeMap<String, Value>,
+}
+
+impl Host {
+    pub fn ipv6(&self) -> Option<&String> {
+        self.nodepool
+            .public_ipv6
+            .as_ref()
+            .or(self.nodepool.private_ipv6.as_ref())
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Group {
+    // Members are listed by name only, the value is always null
+    #[serde(default)]
+    pub hosts: BTreeMap<String, Option<Value>>,
+    #[serde(default)]
+    pub children: BTreeMap<String, Group>,
+    #[serde(default)]
+    pub vars: Mapping,
+}
+
+impl Group {
+    /*
+     * A host is member of a group if listed in it or in any child group
+     */
+    fn contains(&self, host: &str) -> bool {
+        self.hosts.contains_key(host) || self.children.values().any(|child| child.contains(host))
+    }
+
+    fn merge_vars(&self, host: &str, vars: &mut Mapping) {
+        if !self.contains(host) {
+            return;
+        }
+        vars.extend(self.vars.clone());
+        for child in self.children.values() {
+            child.merge_vars(host, vars);
+        }
+    }
+
+    fn collect(&self, name: &str, host: &str, groups: &mut Vec<String>) {
+        if self.contains(host) {
+            groups.push(name.to_string());
+        }
+        for (child_name, child) in self.children.iter() {
+            child.collect(child_name, host, groups);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct All {
+    #[serde(default)]
+    pub hosts: BTreeMap<String, Host>,
+    #[serde(default)]
+    pub children: BTreeMap<String, Group>,
+    #[serde(default)]
+    pub vars: Mapping,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Inventory {
+    pub all: All,
+    // Host entries as written in the inventory, with every variable
+    #[serde(skip)]
+    hosts: BTreeMap<String, Mapping>,
+}
+
+impl Inventory {
+    pub fn parse(store: &str) -> AnyhowResult<Inventory> {
+        let mut inventory: Inventory = serde_yaml::from_str(store)?;
+
+        let raw: Value = serde_yaml::from_str(store)?;
+        if let Some(Value::Mapping(hosts)) = raw.get("all").and_then(|all| all.get("hosts")) {
+            for (name, host) in hosts.iter() {
+                let Some(name) = name.as_str() else {
+                    continue;
+                };
+                let vars = match host {
+                    Value::Mapping(vars) => vars.clone(),
+                    _ => Mapping::new(),
+                };
+                inventory.hosts.insert(name.to_string(), vars);
+            }
+        }
+        Ok(inventory)
+    }
+
+    /*
+     * Every variable of the host entry, ansible_host and nodepool included
+     */
+    pub fn host_vars(&self, host: &str) -> Option<&Mapping> {
+        self.hosts.get(host)
+    }
+
+    /*
+     * Names of every group the host is member of, sorted by nesting. A group
+     * reached through several parents is listed once.
+     */
+    pub fn groups(&self, host: &str) -> Vec<String> {
+        let mut groups: Vec<String> = vec![];
+        for (name, group) in self.all.children.iter() {
+            group.collect(name, host, &mut groups);
+        }
+        let mut seen: BTreeSet<String> = BTreeSet::new();
+        groups.retain(|group| seen.insert(group.clone()));
+        groups
+    }
+
+    /*
+     * The variables as seen by the host: all vars, overridden by group vars
+     * from parent to child, overridden by host vars
+     */
+    pub fn merged_vars(&self, host: &str) -> Option<Mapping> {
+        let host_vars = self.host_vars(host)?;
+
+        let mut vars = self.all.vars.clone();
+        for group in self.all.children.values() {
+            group.merge_vars(host, &mut vars);
+        }
+        vars.extend(host_vars.clone());
+        Some(vars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVENTORY: &str = "
+all:
+  hosts:
+    controller:
+      ansible_host: 10.0.0.5
+      tox_env: py312
+    compute:
+      ansible_host: 10.0.0.6
+  children:
+    computes:
+      hosts:
+        compute: null
+      vars:
+        ansible_port: 2222
+        tox_env: py311
+  vars:
+    ansible_user: zuul
+    tox_env: py310
+";
+
+    #[test]
+    fn merged_vars_precedence() {
+        let inventory = Inventory::parse(INVENTORY).unwrap();
+        let get = |vars: &Mapping, key: &str| vars.get(key).cloned();
+
+        // Unset host fields don't override group or all vars
+        let compute = inventory.merged_vars("compute").unwrap();
+        assert_eq!(get(&compute, "ansible_user"), Some(Value::from("zuul")));
+        assert_eq!(get(&compute, "ansible_port"), Some(Value::from(2222)));
+        assert_eq!(get(&compute, "tox_env"), Some(Value::from("py311")));
+        assert_eq!(get(&compute, "host_keys"), None);
+        assert_eq!(get(&compute, "nodepool"), None);
+
+        let controller = inventory.merged_vars("controller").unwrap();
+        assert_eq!(get(&controller, "tox_env"), Some(Value::from("py312")));
+        assert_eq!(get(&controller, "ansible_port"), None);
+
+        assert!(inventory.merged_vars("missing").is_none());
+    }
+
+    #[test]
+    fn groups_listed_once() {
+        let inventory = Inventory::parse(
+            "
+all:
+  hosts:
+    node: {}
+  children:
+    a:
+      children:
+        shared:
+          hosts: {node: null}
+    b:
+      children:
+        shared:
+          hosts: {node: null}
+    other:
+      hosts: {}
+",
+        )
+        .unwrap();
+        assert_eq!(inventory.groups("node"), vec!["a", "shared", "b"]);
+        assert!(inventory.groups("missing").is_empty());
+    }
+
+    #[test]
+    fn host_vars_complete() {
+        let inventory = Inventory::parse(INVENTORY).unwrap();
+        let controller = inventory.host_vars("controller").unwrap();
+        assert_eq!(
+            controller.get("ansible_host"),
+            Some(&Value::from("10.0.0.5"))
+        );
+        assert_eq!(controller.get("tox_env"), Some(&Value::from("py312")));
+    }
+}
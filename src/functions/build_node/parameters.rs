@@ -3,5 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Parameters {
     pub build_id: String,
+    pub buildset: Option<String>,
     pub force: bool,
+    pub groups: bool,
+    pub vars: Option<String>,
+    pub ipv6: bool,
 }
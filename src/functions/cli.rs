@@ -1,4 +1,6 @@
 use crate::functions::build_node;
+use crate::functions::ssh;
+use crate::functions::vars;
 use clap::{Parser, Subcommand};
 
 #[derive(Debug, Parser, PartialEq)]
@@ -11,4 +13,10 @@ pub struct Functions {
 pub enum FunctionCommand {
     /// Build Node information
     BuildNodes(build_node::cli::BuildNode),
+
+    /// SSH into a build node
+    Ssh(ssh::cli::Ssh),
+
+    /// Job and host variables of a build
+    Vars(vars::cli::Vars),
 }
@@ -0,0 +1,24 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Ssh {
+    /// Build ID
+    #[arg(required = true)]
+    pub build_id: String,
+
+    /// Node name, required when the build has several nodes
+    #[arg(long, short)]
+    pub node: Option<String>,
+
+    /// Print the ssh command instead of running it
+    #[arg(long, short, default_value_t = false, conflicts_with = "ssh_config")]
+    pub print: bool,
+
+    /// Print a ssh_config snippet for all nodes of the build
+    #[arg(long, default_value_t = false)]
+    pub ssh_config: bool,
+
+    /// Don't use cache if any
+    #[arg(long, short, default_value_t = false)]
+    pub force: bool,
+}
@@ -0,0 +1,179 @@
+use crate::builds::builds_struct::Target;
+use crate::config::Config;
+use crate::functions::build_node::command::{_Node, BuildNode};
+use crate::functions::ssh::parameters::Parameters;
+use anyhow::Result as AnyhowResult;
+use log;
+use std::os::unix::process::CommandExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[derive(Debug)]
+pub struct Ssh {
+    pub result: Vec<_Node>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Ssh {
+    pub fn new(config: Config) -> AnyhowResult<Ssh> {
+        Ok(Self {
+            result: vec![],
+            config,
+            parameters: Parameters {
+                build_id: String::new(),
+                node: None,
+                print: false,
+                ssh_config: false,
+                force: false,
+            },
+        })
+    }
+
+    pub fn build_id(&mut self, build_id: String) -> AnyhowResult<&mut Self> {
+        self.parameters.build_id = build_id;
+        Ok(self)
+    }
+
+    pub fn node(&mut self, node: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.node = node;
+        Ok(self)
+    }
+
+    pub fn print(&mut self, print: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.print = print;
+        Ok(self)
+    }
+
+    pub fn ssh_config(&mut self, ssh_config: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.ssh_config = ssh_config;
+        Ok(self)
+    }
+
+    pub fn force(&mut self, force: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.force = force;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut build_node = BuildNode::new(self.config.clone())?;
+        build_node
+            .build_id(self.parameters.build_id.clone())?
+            .force(self.parameters.force)?
+            .runner()?;
+
+        let mut nodes = std::mem::take(&mut build_node.result);
+        if let Some(name) = &self.parameters.node {
+            nodes.retain(|node| &node.name == name);
+            if nodes.is_empty() {
+                anyhow::bail!("No node: {name} in build: {}", self.parameters.build_id);
+            }
+        }
+
+        self.result = nodes;
+        Ok(self)
+    }
+
+    /*
+     * Write the host keys from the inventory to a known_hosts file next to
+     * the cached inventory, None if the inventory has no host keys
+     */
+    fn known_hosts(&self, node: &_Node) -> AnyhowResult<Option<PathBuf>> {
+        if node.host_keys.is_empty() {
+            return Ok(None);
+        }
+
+        let target = Target::new(node.build.clone(), self.config.cache.clone());
+        let path = target.dir.join(format!("known_hosts-{}", node.name));
+        let ip = Self::ip(node)?;
+        let host = match node.port {
+            Some(port) if port != 22 => format!("[{ip}]:{port}"),
+            _ => ip.to_string(),
+        };
+        let lines: Vec<String> = node
+            .host_keys
+            .iter()
+            .map(|key| format!("{host} {key}"))
+            .collect();
+        std::fs::write(&path, lines.join("\n") + "\n")?;
+        Ok(Some(path))
+    }
+
+    fn ip(node: &_Node) -> AnyhowResult<&str> {
+        match node.ip.as_deref() {
+            Some(ip) => Ok(ip),
+            None => anyhow::bail!("No ansible_host for node: {}", node.name),
+        }
+    }
+
+    fn ssh_args(&self, node: &_Node) -> AnyhowResult<Vec<String>> {
+        let ip = Self::ip(node)?;
+        let mut args: Vec<String> = vec![];
+        if let Some(port) = node.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        if let Some(known_hosts) = self.known_hosts(node)? {
+            args.push("-o".to_string());
+            args.push(format!("UserKnownHostsFile={}", known_hosts.display()));
+        }
+        match &node.user {
+            Some(user) => args.push(format!("{user}@{ip}")),
+            None => args.push(ip.to_string()),
+        }
+        Ok(args)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.parameters.ssh_config {
+            true => self._show_ssh_config()?,
+            false => self._exec()?,
+        }
+        Ok(())
+    }
+
+    fn _show_ssh_config(&mut self) -> AnyhowResult<()> {
+        let short: String = self.parameters.build_id.chars().take(7).collect();
+        for node in self.result.iter() {
+            println!("Host zuul-{short}-{}", node.name);
+            println!("    HostName {}", Self::ip(node)?);
+            if let Some(user) = &node.user {
+                println!("    User {user}");
+            }
+            if let Some(port) = node.port {
+                println!("    Port {port}");
+            }
+            if let Some(known_hosts) = self.known_hosts(node)? {
+                println!("    UserKnownHostsFile {}", known_hosts.display());
+            }
+            println!();
+        }
+        Ok(())
+    }
+
+    fn _exec(&mut self) -> AnyhowResult<()> {
+        let node = match self.result.len() {
+            1 => &self.result[0],
+            0 => anyhow::bail!("No nodes in build: {}", self.parameters.build_id),
+            _ => {
+                let names: Vec<&str> = self.result.iter().map(|n| n.name.as_str()).collect();
+                anyhow::bail!(
+                    "Several nodes, select one with --node: {}",
+                    names.join(", ")
+                );
+            }
+        };
+
+        let args = self.ssh_args(node)?;
+        if self.parameters.print {
+            println!("ssh {}", args.join(" "));
+            return Ok(());
+        }
+
+        log::debug!("exec ssh {args:?}");
+        // Only returns on failure
+        let err = Command::new("ssh").args(&args).exec();
+        Err(err.into())
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub build_id: String,
+    pub node: Option<String>,
+    pub print: bool,
+    pub ssh_config: bool,
+    pub force: bool,
+}
@@ -0,0 +1,25 @@
+use crate::enums::vars_format::VarsFormat;
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Vars {
+    /// Build ID
+    #[arg(required = true)]
+    pub build_id: String,
+
+    /// Select a value with a jq-like path, e.g. zuul.items[0]
+    #[arg(long, short)]
+    pub path: Option<String>,
+
+    /// Show the variables as seen by this host
+    #[arg(long, short, value_name = "HOST")]
+    pub merged_host: Option<String>,
+
+    /// Variables format, `--output json` implies json
+    #[arg(long, default_value_t = VarsFormat::Tree)]
+    pub format: VarsFormat,
+
+    /// Don't use cache if any
+    #[arg(long, short, default_value_t = false)]
+    pub force: bool,
+}
@@ -0,0 +1,102 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::enums::vars_format::VarsFormat;
+use crate::functions::build_node::command::BuildNode;
+use crate::functions::vars::parameters::Parameters;
+use crate::util::{tree, value_path};
+use anyhow::Result as AnyhowResult;
+use log;
+use serde_yaml::Value;
+
+#[derive(Debug)]
+pub struct Vars {
+    pub result: Option<Value>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Vars {
+    pub fn new(config: Config) -> AnyhowResult<Vars> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                build_id: String::new(),
+                path: None,
+                merged_host: None,
+                format: VarsFormat::Tree,
+                force: false,
+            },
+        })
+    }
+
+    pub fn build_id(&mut self, build_id: String) -> AnyhowResult<&mut Self> {
+        self.parameters.build_id = build_id;
+        Ok(self)
+    }
+
+    pub fn path(&mut self, path: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.path = path;
+        Ok(self)
+    }
+
+    pub fn merged_host(&mut self, merged_host: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.merged_host = merged_host;
+        Ok(self)
+    }
+
+    pub fn format(&mut self, format: VarsFormat) -> AnyhowResult<&mut Self> {
+        self.parameters.format = format;
+        Ok(self)
+    }
+
+    pub fn force(&mut self, force: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.force = force;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut build_node = BuildNode::new(self.config.clone())?;
+        build_node
+            .build_id(self.parameters.build_id.clone())?
+            .force(self.parameters.force)?
+            .runner()?;
+        let inventory = build_node.inventories.remove(0);
+
+        let vars = match &self.parameters.merged_host {
+            Some(host) => match inventory.merged_vars(host) {
+                Some(vars) => vars,
+                None => anyhow::bail!("No host: {host} in inventory"),
+            },
+            None => inventory.all.vars,
+        };
+        let vars = Value::Mapping(vars);
+
+        let selected = match &self.parameters.path {
+            Some(path) => match value_path::select(&vars, path)? {
+                Some(value) => value.clone(),
+                None => anyhow::bail!("Nothing at path: {path}"),
+            },
+            None => vars,
+        };
+
+        self.result = Some(selected);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        let format = match self.config.output {
+            Output::JSON => VarsFormat::Json,
+            Output::USER => self.parameters.format,
+        };
+        let result = self.result.as_ref().unwrap();
+
+        match format {
+            VarsFormat::Json => println!("{}", serde_json::to_string(result)?),
+            VarsFormat::Yaml => print!("{}", serde_yaml::to_string(result)?),
+            VarsFormat::Tree => print!("{}", tree::render(result)),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use crate::enums::vars_format::VarsFormat;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub build_id: String,
+    pub path: Option<String>,
+    pub merged_host: Option<String>,
+    pub format: VarsFormat,
+    pub force: bool,
+}
@@ -0,0 +1,19 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Jobs {
+    /// Filter job names on regex
+    #[arg(long, short)]
+    pub regex: Option<String>,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Job {
+    /// Job name
+    #[arg(required = true)]
+    pub name: String,
+
+    /// Resolve the parent inheritance chain
+    #[arg(long, short, default_value_t = false)]
+    pub tree: bool,
+}
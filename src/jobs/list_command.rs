@@ -0,0 +1,89 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::jobs::list_parameters::Parameters;
+use crate::jobs::list_struct::JobsResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct ListJobs {
+    pub result: Option<Vec<JobsResult>>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl ListJobs {
+    pub fn new(config: Config) -> AnyhowResult<ListJobs> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters { regex: None },
+        })
+    }
+
+    pub fn regex(&mut self, regex: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.regex = regex;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/jobs",
+            self.config.host, self.config.tenant,
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<JobsResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn filter(&mut self) -> AnyhowResult<&mut Self> {
+        if let Some(regex) = &self.parameters.regex {
+            let regexp = Regex::new(regex)?;
+            self.result
+                .as_mut()
+                .unwrap()
+                .retain(|job| regexp.is_match(&job.name));
+        }
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Job", "Description", "Tags"]);
+
+        for job in self.result.as_ref().unwrap() {
+            table.add_row(vec![
+                Cell::new(job.name.clone()),
+                Cell::new(job.description.clone().unwrap_or_default()),
+                Cell::new(job.tags.join(", ")),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::jobs::show_parameters::Parameters;
+use crate::jobs::show_struct::{JobResult, JobTree, SourceContext, TreeVariant};
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct ShowJob {
+    pub result: Option<Vec<JobResult>>,
+    // Inheritance of every variant, the job itself at the root
+    pub tree: Option<JobTree>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl ShowJob {
+    pub fn new(config: Config) -> AnyhowResult<ShowJob> {
+        Ok(Self {
+            result: None,
+            tree: None,
+            config,
+            parameters: Parameters {
+                name: String::new(),
+                tree: false,
+            },
+        })
+    }
+
+    pub fn name(&mut self, name: String) -> AnyhowResult<&mut Self> {
+        self.parameters.name = name;
+        Ok(self)
+    }
+
+    pub fn tree(&mut self, tree: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.tree = tree;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let variants = self.request(&self.parameters.name)?;
+
+        if self.parameters.tree {
+            let mut jobs: HashMap<String, Vec<JobResult>> = HashMap::new();
+            jobs.insert(self.parameters.name.clone(), variants);
+            let mut path: Vec<String> = vec![];
+            self.tree = Some(self.resolve(&self.parameters.name, &mut jobs, &mut path)?);
+            self.result = jobs.remove(&self.parameters.name);
+            return Ok(self);
+        }
+
+        self.result = Some(variants);
+        Ok(self)
+    }
+
+    fn request(&self, name: &str) -> AnyhowResult<Vec<JobResult>> {
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/job/{}",
+            self.config.host, self.config.tenant, name
+        );
+        send_receive(&mut data, &url, &self.config.http);
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /*
+     * Walk up the parents of every variant, `path` holds the jobs from the
+     * root to `name` to detect loops. Jobs are requested once. Only the
+     * parents reported by Zuul are followed, a variant without one inherits
+     * from the tenant default-parent or is a base job, the API does not tell.
+     */
+    fn resolve(
+        &self,
+        name: &str,
+        jobs: &mut HashMap<String, Vec<JobResult>>,
+        path: &mut Vec<String>,
+    ) -> AnyhowResult<JobTree> {
+        if path.iter().any(|job| job == name) {
+            anyhow::bail!("Inheritance loop at job: {name}");
+        }
+        if !jobs.contains_key(name) {
+            let variants = self.request(name)?;
+            jobs.insert(name.to_string(), variants);
+        }
+
+        let contexts: Vec<(Vec<String>, Option<SourceContext>, Option<String>)> = jobs[name]
+            .iter()
+            .map(|variant| {
+                (
+                    variant.branches.clone(),
+                    variant.source_context.clone(),
+                    variant.parent.clone(),
+                )
+            })
+            .collect();
+
+        path.push(name.to_string());
+        let mut variants: Vec<TreeVariant> = vec![];
+        for (branches, source_context, parent) in contexts {
+            let parent = match parent {
+                Some(parent) => Some(self.resolve(&parent, jobs, path)?),
+                None => None,
+            };
+            variants.push(TreeVariant {
+                branches,
+                source_context,
+                parent,
+            });
+        }
+        path.pop();
+
+        Ok(JobTree {
+            name: name.to_string(),
+            variants,
+        })
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        match self.parameters.tree {
+            true => println!("{}", serde_json::to_string(&self.tree)?),
+            false => println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?),
+        }
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        match self.parameters.tree {
+            true => self._show_user_tree()?,
+            false => self._show_user_variants()?,
+        }
+        Ok(())
+    }
+
+    fn _show_user_tree(&mut self) -> AnyhowResult<()> {
+        let tree = self.tree.as_ref().unwrap();
+        println!("{}", tree.name);
+        Self::print_variants(tree, "");
+        Ok(())
+    }
+
+    /*
+     * One line per variant with its branches and source context, its parent
+     * job below it
+     */
+    fn print_variants(tree: &JobTree, prefix: &str) {
+        for (index, variant) in tree.variants.iter().enumerate() {
+            let last = index + 1 == tree.variants.len();
+            let (branch, indent) = match last {
+                true => ("└─", "   "),
+                false => ("├─", "│  "),
+            };
+
+            let branches = match variant.branches.is_empty() {
+                true => "*".to_string(),
+                false => variant.branches.join(","),
+            };
+            let source = match &variant.source_context {
+                Some(ctx) => format!("{}@{}:{}", ctx.project, ctx.branch, ctx.path),
+                None => "N/A".to_string(),
+            };
+            println!("{prefix}{branch} variant {index} [{branches}] {source}");
+
+            let prefix = format!("{prefix}{indent}");
+            match &variant.parent {
+                Some(parent) => {
+                    println!("{prefix}└─ {}", parent.name);
+                    Self::print_variants(parent, &format!("{prefix}   "));
+                }
+                None => println!("{prefix}└─ (default parent or base job)"),
+            }
+        }
+    }
+
+    fn _show_user_variants(&mut self) -> AnyhowResult<()> {
+        for (index, variant) in self.result.as_ref().unwrap().iter().enumerate() {
+            let branches = match variant.branches.is_empty() {
+                true => "*".to_string(),
+                false => variant.branches.join("\n"),
+            };
+
+            let nodeset = match &variant.nodeset {
+                Some(nodeset) => match nodeset["name"].as_str() {
+                    Some(name) => name.to_string(),
+                    // Anonymous nodeset, list the node labels
+                    None => nodeset["nodes"]
+                        .as_array()
+                        .map(|nodes| {
+                            nodes
+                                .iter()
+                                .filter_map(|node| node["label"].as_str())
+                                .collect::<Vec<&str>>()
+                                .join("\n")
+                        })
+                        .unwrap_or_default(),
+                },
+                None => "N/A".to_string(),
+            };
+
+            let timeout = match variant.timeout {
+                Some(value) => format!("{value}s"),
+                None => "N/A".to_string(),
+            };
+
+            let required_projects: Vec<String> = variant
+                .required_projects
+                .iter()
+                .map(|project| match &project.override_checkout {
+                    Some(checkout) => format!("{} ({checkout})", project.project_name),
+                    None => project.project_name.clone(),
+                })
+                .collect();
+
+            let vars = match variant.variables.as_object() {
+                Some(map) if !map.is_empty() => serde_yaml::to_string(&variant.variables)?,
+                _ => String::new(),
+            };
+
+            let source = match &variant.source_context {
+                Some(ctx) => format!("{}@{}:{}", ctx.project, ctx.branch, ctx.path),
+                None => "N/A".to_string(),
+            };
+
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_BORDERS_ONLY)
+                .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+                .set_header(vec![format!("Variant {}", index), variant.name.clone()]);
+            table.add_row(vec![
+                "Description",
+                variant.description.as_deref().unwrap_or(""),
+            ]);
+            table.add_row(vec!["Parent", variant.parent.as_deref().unwrap_or("N/A")]);
+            table.add_row(vec!["Branches", &branches]);
+            table.add_row(vec!["Nodeset", &nodeset]);
+            table.add_row(vec!["Timeout", &timeout]);
+            table.add_row(vec!["Required projects", &required_projects.join("\n")]);
+            table.add_row(vec!["Vars", vars.trim_end()]);
+            table.add_row(vec!["Source", &source]);
+            println!("{table}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceContext {
+    pub project: String,
+    pub branch: String,
+    pub path: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequiredProject {
+    pub project_name: String,
+    pub override_branch: Option<String>,
+    pub override_checkout: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobResult {
+    pub name: String,
+    pub parent: Option<String>,
+    pub description: Option<String>,
+    pub variant_description: Option<String>,
+    #[serde(default)]
+    pub branches: Vec<String>,
+    pub nodeset: Option<serde_json::Value>,
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    pub required_projects: Vec<RequiredProject>,
+    #[serde(default)]
+    pub variables: serde_json::Value,
+    pub source_context: Option<SourceContext>,
+    #[serde(flatten)]
+    pub other: serde_json::Value,
+}
+
+/*
+ * A job of the inheritance tree with the parent of each of its variants
+ */
+#[derive(Debug, Serialize)]
+pub struct JobTree {
+    pub name: String,
+    pub variants: Vec<TreeVariant>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TreeVariant {
+    pub branches: Vec<String>,
+    pub source_context: Option<SourceContext>,
+    pub parent: Option<JobTree>,
+}
@@ -3,9 +3,15 @@ extern crate log;
 extern crate serde_json;
 
 pub mod util {
+    pub mod change_ref;
     pub mod diffdatetime_now;
+    pub mod duration;
     pub mod easy;
     pub mod helpers;
+    pub mod timestamp;
+    pub mod tree;
+    pub mod value_path;
+    pub mod window;
 }
 
 pub mod buildsets {
@@ -17,21 +23,37 @@ pub mod buildsets {
 
 pub mod autohold {
     pub mod cli;
+    pub mod expiring_command;
+    pub mod expiring_parameters;
     pub mod list_command;
     pub mod list_parameters;
     pub mod list_struct;
+    pub mod nodes_command;
+    pub mod nodes_parameters;
+    pub mod renew_command;
+    pub mod renew_parameters;
 }
 
 pub mod builds {
     pub mod builds_struct;
     pub mod cli;
     pub mod command;
+    pub mod diff_command;
+    pub mod diff_parameters;
+    pub mod diff_struct;
+    pub mod job_output_struct;
     pub mod parameters;
 }
 
 pub mod enums {
     pub mod bsresult;
+    pub mod hold_sort;
+    pub mod hold_state;
+    pub mod node_state;
     pub mod output;
+    pub mod queue_action;
+    pub mod severity;
+    pub mod vars_format;
 }
 
 pub mod functions {
@@ -39,8 +61,114 @@ pub mod functions {
     pub mod build_node {
         pub mod cli;
         pub mod command;
+        pub mod inventory_struct;
         pub mod parameters;
     }
+    pub mod ssh {
+        pub mod cli;
+        pub mod command;
+        pub mod parameters;
+    }
+    pub mod vars {
+        pub mod cli;
+        pub mod command;
+        pub mod parameters;
+    }
+}
+
+pub mod tenants {
+    pub mod cli;
+    pub mod command;
+    pub mod tenants_struct;
+}
+
+pub mod projects {
+    pub mod cli;
+    pub mod list_command;
+    pub mod list_struct;
+    pub mod show_command;
+    pub mod show_parameters;
+    pub mod show_struct;
+}
+
+pub mod jobs {
+    pub mod cli;
+    pub mod list_command;
+    pub mod list_parameters;
+    pub mod list_struct;
+    pub mod show_command;
+    pub mod show_parameters;
+    pub mod show_struct;
+}
+
+pub mod pipelines {
+    pub mod cli;
+    pub mod list_command;
+    pub mod pipelines_struct;
+    pub mod queues_command;
+    pub mod queues_parameters;
+    pub mod status_struct;
+}
+
+pub mod nodes {
+    pub mod cli;
+    pub mod labels_command;
+    pub mod labels_struct;
+    pub mod list_command;
+    pub mod list_parameters;
+    pub mod list_struct;
+}
+
+pub mod semaphores {
+    pub mod cli;
+    pub mod command;
+    pub mod semaphores_struct;
+}
+
+pub mod config_errors {
+    pub mod cli;
+    pub mod command;
+    pub mod errors_struct;
+    pub mod parameters;
+}
+
+pub mod freeze_jobs {
+    pub mod cli;
+    pub mod command;
+    pub mod freeze_struct;
+    pub mod parameters;
+}
+
+pub mod system {
+    pub mod cli;
+    pub mod components_command;
+    pub mod components_parameters;
+    pub mod components_struct;
+    pub mod info_command;
+    pub mod info_struct;
+}
+
+pub mod manage {
+    pub mod cli;
+    pub mod promote_command;
+    pub mod promote_parameters;
+    pub mod queue_command;
+    pub mod queue_parameters;
+}
+
+pub mod change {
+    pub mod change_struct;
+    pub mod cli;
+    pub mod command;
+    pub mod parameters;
+}
+
+pub mod tui {
+    pub mod app;
+    pub mod cli;
+    pub mod command;
+    pub mod parameters;
+    pub mod ui;
 }
 
 pub mod cli_struct;
@@ -1,12 +1,26 @@
 use anyhow::Result as AnyhowResult;
+use clap::builder::PossibleValuesParser;
 use clap::{Command, CommandFactory, Parser};
 use clap_complete::{generate, Generator};
 use sol::autohold;
 use sol::builds;
 use sol::buildsets;
+use sol::change;
 use sol::cli_struct::{Cli, Commands};
 use sol::config::Config;
+use sol::config_errors;
+use sol::enums::queue_action::QueueAction;
+use sol::freeze_jobs;
 use sol::functions;
+use sol::jobs;
+use sol::manage;
+use sol::nodes;
+use sol::pipelines;
+use sol::projects;
+use sol::semaphores;
+use sol::system;
+use sol::tenants;
+use sol::tui;
 use std::io;
 
 /*
@@ -24,6 +38,17 @@ fn main() -> AnyhowResult<()> {
     // generator
     if let Some(generator) = cli.generator {
         let mut cmd = Cli::command();
+
+        // Complete --tenant from the cached tenants list
+        if let Some(config) = Config::try_load(&cli) {
+            let names = tenants::command::Tenants::cached_names(&config.cache);
+            if !names.is_empty() {
+                cmd = cmd.mut_arg("tenant", |arg| {
+                    arg.value_parser(PossibleValuesParser::new(names))
+                });
+            }
+        }
+
         eprintln!("Generating completion file for {:?}...", generator);
         print_completions(generator, &mut cmd);
         return Ok(());
@@ -37,42 +62,267 @@ fn main() -> AnyhowResult<()> {
         Some(Commands::Functions(func)) => match &func.command {
             functions::cli::FunctionCommand::BuildNodes(bn) => {
                 functions::build_node::command::BuildNode::new(config)?
-                    .build_id(bn.build_id.clone())?
+                    .build_id(bn.build_id.clone().unwrap_or_default())?
+                    .buildset(bn.buildset.clone())?
                     .force(bn.force)?
+                    .groups(bn.groups)?
+                    .vars(bn.vars.clone())?
+                    .ipv6(bn.ipv6)?
+                    .runner()?
+                    .show()?;
+            }
+            functions::cli::FunctionCommand::Ssh(ssh) => {
+                functions::ssh::command::Ssh::new(config)?
+                    .build_id(ssh.build_id.clone())?
+                    .node(ssh.node.clone())?
+                    .print(ssh.print)?
+                    .ssh_config(ssh.ssh_config)?
+                    .force(ssh.force)?
+                    .runner()?
+                    .show()?;
+            }
+            functions::cli::FunctionCommand::Vars(vars) => {
+                functions::vars::command::Vars::new(config)?
+                    .build_id(vars.build_id.clone())?
+                    .path(vars.path.clone())?
+                    .merged_host(vars.merged_host.clone())?
+                    .format(vars.format)?
+                    .force(vars.force)?
+                    .runner()?
+                    .show()?;
+            }
+        },
+        Some(Commands::Builds(builds)) => match &builds.command {
+            Some(builds::cli::BuildsCommand::Diff {
+                uuid_a,
+                uuid_b,
+                threshold,
+                force,
+            }) => {
+                builds::diff_command::DiffBuilds::new(config)?
+                    .uuids(uuid_a.clone(), uuid_b.clone())?
+                    .threshold(*threshold)?
+                    .force(*force)?
+                    .runner()?
+                    .show()?;
+            }
+            None => {
+                builds::command::Builds::new(config)?
+                    .job_name(builds.job_name.clone())?
+                    .uuid(builds.uuid.clone())?
+                    .change(builds.change.clone())?
+                    .patchset(builds.patchset.clone())?
+                    .force(builds.force)?
+                    .verbose(builds.verbose)?
+                    .all_tenants(builds.all_tenants)?
+                    .since(builds.since)?
+                    .until(builds.until)?
                     .runner()?
                     .show()?;
             }
         },
-        Some(Commands::Builds(builds)) => {
-            builds::command::Builds::new(config)?
-                .job_name(builds.job_name.clone())?
-                .uuid(builds.uuid.clone())?
-                .change(builds.change.clone())?
-                .patchset(builds.patchset.clone())?
-                .force(builds.force)?
-                .verbose(builds.verbose)?
-                .runner()?
-                .show()?;
-        }
         Some(Commands::BuildSets(bs)) => {
             buildsets::command::BuildSets::new(config)?
                 .result(bs.result.clone())?
                 .project(bs.group.project.clone())?
                 .change(bs.group.change.clone())?
                 .uuid(bs.group.uuid.clone())?
+                .all_tenants(bs.all_tenants)?
+                .since(bs.since)?
+                .until(bs.until)?
                 .runner()?
                 .show()?;
         }
         Some(Commands::AutoHold(ah)) => match &ah.command {
-            autohold::cli::AutoHoldCommand::List { user, snapped } => {
+            autohold::cli::AutoHoldCommand::List {
+                user,
+                snapped,
+                state,
+                sort,
+                all_tenants,
+                owner,
+                project,
+                job,
+                since,
+                until,
+            } => {
                 autohold::list_command::ListAutoHold::new(config)?
                     .user(user.clone())?
                     .snapped(*snapped)?
+                    .all_tenants(*all_tenants)?
+                    .owner(owner.clone())?
+                    .project(project.clone())?
+                    .job(job.clone())?
+                    .state(*state)?
+                    .sort(*sort)?
+                    .since(*since)?
+                    .until(*until)?
                     .runner()?
                     .filter()?
                     .show()?;
             }
+            autohold::cli::AutoHoldCommand::Nodes { user, force } => {
+                autohold::nodes_command::HeldNodes::new(config)?
+                    .user(user.clone())?
+                    .force(*force)?
+                    .runner()?
+                    .show()?;
+            }
+            autohold::cli::AutoHoldCommand::Expiring { within, user } => {
+                let mut expiring = autohold::expiring_command::ExpiringAutoHold::new(config)?;
+                expiring
+                    .within(*within)?
+                    .user(user.clone())?
+                    .runner()?
+                    .show()?;
+                if expiring.found() {
+                    std::process::exit(1);
+                }
+            }
+            autohold::cli::AutoHoldCommand::Renew {
+                id,
+                expiration,
+                delete_old,
+                yes,
+            } => {
+                autohold::renew_command::RenewAutoHold::new(config)?
+                    .id(id.clone())?
+                    .expiration(*expiration)?
+                    .delete_old(*delete_old)?
+                    .yes(*yes)?
+                    .runner()?
+                    .show()?;
+            }
         },
+        Some(Commands::Tenants(_)) => {
+            tenants::command::Tenants::new(config)?.runner()?.show()?;
+        }
+        Some(Commands::Projects(_)) => {
+            projects::list_command::ListProjects::new(config)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Project(project)) => {
+            projects::show_command::ShowProject::new(config)?
+                .name(project.name.clone())?
+                .pipeline(project.pipeline.clone())?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Jobs(jobs)) => {
+            jobs::list_command::ListJobs::new(config)?
+                .regex(jobs.regex.clone())?
+                .runner()?
+                .filter()?
+                .show()?;
+        }
+        Some(Commands::Job(job)) => {
+            jobs::show_command::ShowJob::new(config)?
+                .name(job.name.clone())?
+                .tree(job.tree)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Pipelines(_)) => {
+            pipelines::list_command::ListPipelines::new(config)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Queues(queues)) => {
+            pipelines::queues_command::Queues::new(config)?
+                .pipeline(queues.pipeline.clone())?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Labels(_)) => {
+            nodes::labels_command::ListLabels::new(config)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Nodes(nodes)) => {
+            nodes::list_command::ListNodes::new(config)?
+                .state(nodes.state)?
+                .label(nodes.label.clone())?
+                .provider(nodes.provider.clone())?
+                .runner()?
+                .filter()?
+                .show()?;
+        }
+        Some(Commands::Semaphores(_)) => {
+            semaphores::command::Semaphores::new(config)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::ConfigErrors(errors)) => {
+            let mut config_errors = config_errors::command::ConfigErrors::new(config)?;
+            config_errors
+                .project(errors.project.clone())?
+                .severity(errors.severity)?
+                .runner()?
+                .filter()?
+                .show()?;
+            if config_errors.found() {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::FreezeJobs(freeze)) => {
+            freeze_jobs::command::FreezeJobs::new(config)?
+                .project(freeze.project.clone())?
+                .pipeline(freeze.pipeline.clone())?
+                .branch(freeze.branch.clone())?
+                .job(freeze.job.clone())?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Info(_)) => {
+            system::info_command::Info::new(config)?.runner()?.show()?;
+        }
+        Some(Commands::Components(components)) => {
+            let mut command = system::components_command::Components::new(config)?;
+            command.check(components.check)?.runner()?.show()?;
+            if command.failed() {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Enqueue(enqueue)) => {
+            manage::queue_command::QueueChange::new(config, QueueAction::Enqueue)?
+                .pipeline(enqueue.pipeline.clone())?
+                .project(enqueue.project.clone())?
+                .change(enqueue.change.clone())?
+                .ref_(enqueue.ref_.clone())?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Dequeue(dequeue)) => {
+            manage::queue_command::QueueChange::new(config, QueueAction::Dequeue)?
+                .pipeline(dequeue.pipeline.clone())?
+                .project(dequeue.project.clone())?
+                .change(dequeue.change.clone())?
+                .ref_(dequeue.ref_.clone())?
+                .yes(dequeue.yes)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Promote(promote)) => {
+            manage::promote_command::Promote::new(config)?
+                .pipeline(promote.pipeline.clone())?
+                .changes(promote.changes.clone())?
+                .yes(promote.yes)?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Change(change)) => {
+            change::command::Change::new(config)?
+                .change(change.change.clone())?
+                .patchset(change.patchset.clone())?
+                .runner()?
+                .show()?;
+        }
+        Some(Commands::Tui(tui)) => {
+            tui::command::Tui::new(config)?
+                .refresh(tui.refresh)?
+                .runner()?;
+        }
         None => {}
     }
 
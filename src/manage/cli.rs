@@ -0,0 +1,61 @@
+use crate::util::change_ref::ChangeRef;
+use clap::{ArgGroup, Parser};
+
+#[derive(Debug, Parser, PartialEq)]
+#[command(group(ArgGroup::new("target").required(true).args(["change", "ref_"])))]
+pub struct Enqueue {
+    /// Pipeline name
+    #[arg(long, short, required = true)]
+    pub pipeline: String,
+
+    /// Project name, taken from the change when given as URL
+    #[arg(long, short = 'P')]
+    pub project: Option<String>,
+
+    /// Change and patchset: 1234,5 or a review URL
+    #[arg(long, short, value_parser = ChangeRef::parse)]
+    pub change: Option<ChangeRef>,
+
+    /// Git ref, e.g. refs/heads/main
+    #[arg(long = "ref", short)]
+    pub ref_: Option<String>,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+#[command(group(ArgGroup::new("target").required(true).args(["change", "ref_"])))]
+pub struct Dequeue {
+    /// Pipeline name
+    #[arg(long, short, required = true)]
+    pub pipeline: String,
+
+    /// Project name, taken from the change when given as URL
+    #[arg(long, short = 'P')]
+    pub project: Option<String>,
+
+    /// Change and patchset: 1234,5 or a review URL
+    #[arg(long, short, value_parser = ChangeRef::parse)]
+    pub change: Option<ChangeRef>,
+
+    /// Git ref, e.g. refs/heads/main
+    #[arg(long = "ref", short)]
+    pub ref_: Option<String>,
+
+    /// Do not ask for confirmation
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Promote {
+    /// Pipeline name
+    #[arg(long, short, required = true)]
+    pub pipeline: String,
+
+    /// Changes in the new order, as 1234,5, a review URL or a change number queued in the pipeline
+    #[arg(long, short, required = true, num_args = 1.., value_parser = ChangeRef::parse)]
+    pub changes: Vec<ChangeRef>,
+
+    /// Do not ask for confirmation
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+}
@@ -0,0 +1,152 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::manage::promote_parameters::Parameters;
+use crate::pipelines::status_struct::StatusResult;
+use crate::util::change_ref::ChangeRef;
+use crate::util::easy::{send_action, send_receive};
+use crate::util::helpers;
+use anyhow::Result as AnyhowResult;
+use log;
+use serde_json::json;
+
+#[derive(Debug)]
+pub struct Promote {
+    pub result: Option<Vec<String>>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Promote {
+    pub fn new(config: Config) -> AnyhowResult<Promote> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                pipeline: String::new(),
+                changes: vec![],
+                yes: false,
+            },
+        })
+    }
+
+    pub fn pipeline(&mut self, pipeline: String) -> AnyhowResult<&mut Self> {
+        self.parameters.pipeline = pipeline;
+        Ok(self)
+    }
+
+    pub fn changes(&mut self, changes: Vec<ChangeRef>) -> AnyhowResult<&mut Self> {
+        self.parameters.changes = changes;
+        Ok(self)
+    }
+
+    pub fn yes(&mut self, yes: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.yes = yes;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let token = self.config.token()?.to_string();
+        let base = format!(
+            "https://{}/api/tenant/{}",
+            self.config.host, self.config.tenant
+        );
+
+        let changes = self.resolve(&base)?;
+
+        if !self.parameters.yes {
+            let question = format!(
+                "Promote {} in {}, the jobs of the queue are restarted, continue?",
+                changes.join(" "),
+                self.parameters.pipeline
+            );
+            if !helpers::confirm(&question)? {
+                anyhow::bail!("Aborted");
+            }
+        }
+
+        let body = json!({"pipeline": self.parameters.pipeline, "changes": changes});
+        let mut data: Vec<u8> = Vec::new();
+        send_action(
+            &mut data,
+            "POST",
+            &format!("{base}/promote"),
+            Some(&body),
+            &self.config.http,
+            &token,
+        )?;
+
+        self.result = Some(changes);
+        Ok(self)
+    }
+
+    /*
+     * Zuul wants the item ids, e.g. 1234,5. A bare change number is looked
+     * up in the items queued in the pipeline.
+     */
+    fn resolve(&self, base: &str) -> AnyhowResult<Vec<String>> {
+        if self
+            .parameters
+            .changes
+            .iter()
+            .all(|change| change.patchset.is_some())
+        {
+            return Ok(self
+                .parameters
+                .changes
+                .iter()
+                .map(|c| c.to_string())
+                .collect());
+        }
+
+        let mut data: Vec<u8> = Vec::new();
+        send_receive(&mut data, &format!("{base}/status"), &self.config.http);
+        let status: StatusResult = serde_json::from_slice(&data)?;
+        let Some(pipeline) = status
+            .pipelines
+            .iter()
+            .find(|pipeline| pipeline.name == self.parameters.pipeline)
+        else {
+            anyhow::bail!("No pipeline: {}", self.parameters.pipeline);
+        };
+        let ids = pipeline.item_ids();
+
+        let mut changes: Vec<String> = vec![];
+        for change in self.parameters.changes.iter() {
+            if change.patchset.is_some() {
+                changes.push(change.to_string());
+                continue;
+            }
+            let Some(id) = ids
+                .iter()
+                .find(|id| id.split(',').next() == Some(change.change.as_str()))
+            else {
+                anyhow::bail!("Change {change} is not queued in {}", pipeline.name);
+            };
+            changes.push(id.clone());
+        }
+        Ok(changes)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        println!(
+            "Promoted {} in {}",
+            self.result.as_ref().unwrap().join(" "),
+            self.parameters.pipeline
+        );
+        Ok(())
+    }
+}
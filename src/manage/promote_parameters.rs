@@ -0,0 +1,9 @@
+use crate::util::change_ref::ChangeRef;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub pipeline: String,
+    pub changes: Vec<ChangeRef>,
+    pub yes: bool,
+}
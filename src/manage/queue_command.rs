@@ -0,0 +1,166 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::enums::queue_action::QueueAction;
+use crate::manage::queue_parameters::Parameters;
+use crate::util::change_ref::ChangeRef;
+use crate::util::easy::send_action;
+use crate::util::helpers;
+use anyhow::Result as AnyhowResult;
+use log;
+use serde_json::json;
+
+#[derive(Debug)]
+pub struct QueueChange {
+    pub result: Option<serde_json::Value>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl QueueChange {
+    pub fn new(config: Config, action: QueueAction) -> AnyhowResult<QueueChange> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                action,
+                pipeline: String::new(),
+                project: None,
+                change: None,
+                ref_: None,
+                yes: false,
+            },
+        })
+    }
+
+    pub fn pipeline(&mut self, pipeline: String) -> AnyhowResult<&mut Self> {
+        self.parameters.pipeline = pipeline;
+        Ok(self)
+    }
+
+    pub fn project(&mut self, project: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.project = project;
+        Ok(self)
+    }
+
+    pub fn change(&mut self, change: Option<ChangeRef>) -> AnyhowResult<&mut Self> {
+        self.parameters.change = change;
+        Ok(self)
+    }
+
+    pub fn ref_(&mut self, ref_: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.ref_ = ref_;
+        Ok(self)
+    }
+
+    pub fn yes(&mut self, yes: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.yes = yes;
+        Ok(self)
+    }
+
+    fn target(&self) -> String {
+        match &self.parameters.change {
+            Some(change) => change.to_string(),
+            None => self.parameters.ref_.clone().unwrap_or_default(),
+        }
+    }
+
+    /*
+     * The project option wins over the project of a change URL
+     */
+    fn project_name(&self) -> AnyhowResult<String> {
+        let project = self.parameters.project.clone().or(self
+            .parameters
+            .change
+            .as_ref()
+            .and_then(|change| change.project.clone()));
+        match project {
+            Some(project) => Ok(project),
+            None => anyhow::bail!("--project is required unless the change is a review URL"),
+        }
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let token = self.config.token()?.to_string();
+        let action = self.parameters.action;
+        let project = self.project_name()?;
+
+        // Zuul identifies a queue item by change and patchset
+        if let Some(change) = &self.parameters.change {
+            if change.patchset.is_none() {
+                anyhow::bail!("Change {change} needs a patchset, e.g. {change},1");
+            }
+        }
+
+        // Dequeue cancels the running jobs of the item
+        if action == QueueAction::Dequeue && !self.parameters.yes {
+            let question = format!(
+                "Dequeue {} of {} from {}, its running jobs are cancelled, continue?",
+                self.target(),
+                project,
+                self.parameters.pipeline
+            );
+            if !helpers::confirm(&question)? {
+                anyhow::bail!("Aborted");
+            }
+        }
+
+        let body = match &self.parameters.change {
+            Some(change) => {
+                json!({"pipeline": self.parameters.pipeline, "change": change.to_string()})
+            }
+            None => json!({"pipeline": self.parameters.pipeline, "ref": self.parameters.ref_}),
+        };
+
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/project/{}/{action}",
+            self.config.host, self.config.tenant, project
+        );
+        send_action(
+            &mut data,
+            "POST",
+            &url,
+            Some(&body),
+            &self.config.http,
+            &token,
+        )?;
+
+        self.result = Some(json!({
+            "action": action.to_string(),
+            "pipeline": self.parameters.pipeline,
+            "project": project,
+            "target": self.target(),
+            "response": serde_json::from_slice::<serde_json::Value>(&data).ok(),
+        }));
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let verb = match self.parameters.action {
+            QueueAction::Enqueue => "Enqueued",
+            QueueAction::Dequeue => "Dequeued",
+        };
+        let result = self.result.as_ref().unwrap();
+        println!(
+            "{verb} {} of {} in {}",
+            self.target(),
+            result["project"].as_str().unwrap_or("N/A"),
+            self.parameters.pipeline
+        );
+        Ok(())
+    }
+}
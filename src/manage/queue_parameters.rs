@@ -0,0 +1,13 @@
+use crate::enums::queue_action::QueueAction;
+use crate::util::change_ref::ChangeRef;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub action: QueueAction,
+    pub pipeline: String,
+    pub project: Option<String>,
+    pub change: Option<ChangeRef>,
+    pub ref_: Option<String>,
+    pub yes: bool,
+}
@@ -0,0 +1,20 @@
+use crate::enums::node_state::NodeState;
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Labels {}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Nodes {
+    /// Filter on state
+    #[arg(long, short)]
+    pub state: Option<NodeState>,
+
+    /// Filter on label
+    #[arg(long, short)]
+    pub label: Option<String>,
+
+    /// Filter on provider
+    #[arg(long, short)]
+    pub provider: Option<String>,
+}
@@ -0,0 +1,65 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::nodes::labels_struct::LabelsResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct ListLabels {
+    pub result: Option<Vec<LabelsResult>>,
+    pub config: Config,
+}
+
+impl ListLabels {
+    pub fn new(config: Config) -> AnyhowResult<ListLabels> {
+        Ok(Self {
+            result: None,
+            config,
+        })
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/labels",
+            self.config.host, self.config.tenant,
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<LabelsResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Label"]);
+
+        for label in self.result.as_ref().unwrap() {
+            table.add_row(vec![Cell::new(label.name.clone())]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
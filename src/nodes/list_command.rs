@@ -0,0 +1,134 @@
+use crate::config::Config;
+use crate::enums::node_state::NodeState;
+use crate::enums::output::Output;
+use crate::nodes::list_parameters::Parameters;
+use crate::nodes::list_struct::NodesResult;
+use crate::util::easy::send_receive;
+use crate::util::timestamp;
+use anyhow::Result as AnyhowResult;
+use chrono::Utc;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+pub struct ListNodes {
+    pub result: Option<Vec<NodesResult>>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl ListNodes {
+    pub fn new(config: Config) -> AnyhowResult<ListNodes> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                state: None,
+                label: None,
+                provider: None,
+            },
+        })
+    }
+
+    pub fn state(&mut self, state: Option<NodeState>) -> AnyhowResult<&mut Self> {
+        self.parameters.state = state;
+        Ok(self)
+    }
+
+    pub fn label(&mut self, label: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.label = label;
+        Ok(self)
+    }
+
+    pub fn provider(&mut self, provider: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.provider = provider;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/nodes",
+            self.config.host, self.config.tenant,
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<NodesResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn filter(&mut self) -> AnyhowResult<&mut Self> {
+        let parameters = &self.parameters;
+        self.result.as_mut().unwrap().retain(|node| {
+            parameters
+                .state
+                .is_none_or(|state| node.state == state.as_str())
+                && parameters
+                    .label
+                    .as_ref()
+                    .is_none_or(|label| node._type.contains(label))
+                && parameters
+                    .provider
+                    .as_ref()
+                    .is_none_or(|provider| node.provider.as_ref() == Some(provider))
+        });
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec![
+                "ID", "Label", "State", "Since", "Provider", "Address", "Comment", "Lock",
+            ]);
+
+        let now = Utc::now();
+        let mut states: BTreeMap<&str, usize> = BTreeMap::new();
+        for node in self.result.as_ref().unwrap() {
+            *states.entry(node.state.as_str()).or_default() += 1;
+            table.add_row(vec![
+                Cell::new(node.id.clone()),
+                Cell::new(node._type.join("\n")),
+                Cell::new(node.state.clone()),
+                Cell::new(
+                    node.state_time
+                        .map(|time| timestamp::relative(&time, &now))
+                        .unwrap_or_else(|| "N/A".to_string()),
+                ),
+                Cell::new(node.provider.as_deref().unwrap_or("N/A")),
+                Cell::new(node.address().unwrap_or_else(|| "N/A".to_string())),
+                Cell::new(node.comment.as_deref().unwrap_or("")),
+                Cell::new(node.lock_holder.as_deref().unwrap_or("")),
+            ]);
+        }
+        println!("{table}");
+
+        // Capacity summary, e.g. how many nodes are held
+        let summary: Vec<String> = states
+            .iter()
+            .map(|(state, count)| format!("{state}: {count}"))
+            .collect();
+        println!("{}", summary.join(", "));
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use crate::enums::node_state::NodeState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Parameters {
+    pub state: Option<NodeState>,
+    pub label: Option<String>,
+    pub provider: Option<String>,
+}
@@ -0,0 +1,33 @@
+use crate::util::timestamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodesResult {
+    pub id: String,
+    // Labels of the node
+    #[serde(rename = "type", default)]
+    pub _type: Vec<String>,
+    pub connection_type: Option<String>,
+    pub external_id: Option<serde_json::Value>,
+    pub provider: Option<String>,
+    pub state: String,
+    #[serde(default, deserialize_with = "timestamp::deserialize_epoch_option")]
+    pub state_time: Option<DateTime<Utc>>,
+    pub comment: Option<String>,
+    // Only exposed by some Zuul versions
+    pub interface_ip: Option<String>,
+    pub connection_port: Option<u64>,
+    pub lock_holder: Option<String>,
+}
+
+impl NodesResult {
+    pub fn address(&self) -> Option<String> {
+        self.interface_ip
+            .as_ref()
+            .map(|ip| match self.connection_port {
+                Some(port) => format!("{ip}:{port}"),
+                None => ip.clone(),
+            })
+    }
+}
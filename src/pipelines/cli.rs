@@ -0,0 +1,11 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Pipelines {}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Queues {
+    /// Only show queues of this pipeline
+    #[arg(long, short)]
+    pub pipeline: Option<String>,
+}
@@ -0,0 +1,85 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::pipelines::pipelines_struct::PipelinesResult;
+use crate::pipelines::status_struct::StatusResult;
+use crate::util::easy::send_receive_many;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct ListPipelines {
+    pub result: Option<Vec<PipelinesResult>>,
+    pub config: Config,
+}
+
+impl ListPipelines {
+    pub fn new(config: Config) -> AnyhowResult<ListPipelines> {
+        Ok(Self {
+            result: None,
+            config,
+        })
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let base = format!(
+            "https://{}/api/tenant/{}",
+            self.config.host, self.config.tenant
+        );
+
+        // The manager type is only part of the status
+        let urls = vec![format!("{base}/pipelines"), format!("{base}/status")];
+        let bodies = send_receive_many(&urls, self.config.parallel, &self.config.http)?;
+
+        let mut output: Vec<PipelinesResult> = serde_json::from_slice(&bodies[0])?;
+        let status: StatusResult = serde_json::from_slice(&bodies[1])?;
+        for pipeline in output.iter_mut() {
+            pipeline.manager = status
+                .pipelines
+                .iter()
+                .find(|p| p.name == pipeline.name)
+                .and_then(|p| p.manager.clone());
+        }
+
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Pipeline", "Manager", "Triggers"]);
+
+        for pipeline in self.result.as_ref().unwrap() {
+            let triggers: Vec<String> = pipeline
+                .triggers
+                .iter()
+                .map(|trigger| format!("{} ({})", trigger.name, trigger.driver))
+                .collect();
+            table.add_row(vec![
+                Cell::new(pipeline.name.clone()),
+                Cell::new(pipeline.manager.as_deref().unwrap_or("N/A")),
+                Cell::new(triggers.join("\n")),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
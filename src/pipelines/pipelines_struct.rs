@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Trigger {
+    pub name: String,
+    pub driver: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelinesResult {
+    pub name: String,
+    #[serde(default)]
+    pub triggers: Vec<Trigger>,
+    // Not from the pipelines endpoint, joined from the status
+    #[serde(default)]
+    pub manager: Option<String>,
+}
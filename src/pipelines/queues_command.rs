@@ -0,0 +1,109 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::pipelines::queues_parameters::Parameters;
+use crate::pipelines::status_struct::StatusResult;
+use crate::util::easy::try_send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Queue {
+    pub pipeline: String,
+    pub name: String,
+    pub window: Option<usize>,
+    pub depth: usize,
+    pub projects: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Queues {
+    pub result: Vec<Queue>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Queues {
+    pub fn new(config: Config) -> AnyhowResult<Queues> {
+        Ok(Self {
+            result: vec![],
+            config,
+            parameters: Parameters { pipeline: None },
+        })
+    }
+
+    pub fn pipeline(&mut self, pipeline: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.pipeline = pipeline;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/status",
+            self.config.host, self.config.tenant
+        );
+
+        try_send_receive(&mut data, &url, &self.config.http)?;
+        let status: StatusResult = serde_json::from_slice(&data)?;
+
+        for pipeline in status.pipelines.iter() {
+            if let Some(name) = &self.parameters.pipeline {
+                if &pipeline.name != name {
+                    continue;
+                }
+            }
+            for queue in pipeline.change_queues.iter() {
+                self.result.push(Queue {
+                    pipeline: pipeline.name.clone(),
+                    name: queue.name.clone(),
+                    window: queue.window,
+                    depth: queue.depth(),
+                    projects: queue.projects(),
+                });
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result)?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Pipeline", "Queue", "Window", "Depth", "Projects"]);
+
+        for queue in self.result.iter() {
+            let window = match queue.window {
+                Some(0) => "unlimited".to_string(),
+                Some(value) => value.to_string(),
+                None => "N/A".to_string(),
+            };
+            table.add_row(vec![
+                Cell::new(queue.pipeline.clone()),
+                Cell::new(queue.name.clone()),
+                Cell::new(window),
+                Cell::new(queue.depth),
+                Cell::new(queue.projects.join("\n")),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangeQueue {
+    pub name: String,
+    pub window: Option<usize>,
+    // Each head is a chain of dependent items
+    #[serde(default)]
+    pub heads: Vec<Vec<Value>>,
+    #[serde(flatten)]
+    pub other: Value,
+}
+
+impl ChangeQueue {
+    /*
+     * Number of items in the queue
+     */
+    pub fn depth(&self) -> usize {
+        self.heads.iter().map(|head| head.len()).sum()
+    }
+
+    /*
+     * Projects of the queue as listed by Zuul, else of the queued items
+     */
+    pub fn projects(&self) -> Vec<String> {
+        let mut projects: BTreeSet<String> = BTreeSet::new();
+        if let Some(listed) = self.other["projects"].as_array() {
+            for project in listed {
+                if let Some(name) = project.as_str().or(project["name"].as_str()) {
+                    projects.insert(name.to_string());
+                }
+            }
+        }
+        for item in self.heads.iter().flatten() {
+            if let Some(project) = item["project"].as_str() {
+                projects.insert(project.to_string());
+            }
+            // Newer Zuul lists the refs of an item
+            for reference in item["refs"].as_array().into_iter().flatten() {
+                if let Some(project) = reference["project"].as_str() {
+                    projects.insert(project.to_string());
+                }
+            }
+        }
+        projects.into_iter().collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusPipeline {
+    pub name: String,
+    pub manager: Option<String>,
+    #[serde(default)]
+    pub change_queues: Vec<ChangeQueue>,
+    #[serde(flatten)]
+    pub other: Value,
+}
+
+impl StatusPipeline {
+    /*
+     * Ids of the queued items, e.g. 1234,5 for a Gerrit change
+     */
+    pub fn item_ids(&self) -> Vec<String> {
+        let mut ids: BTreeSet<String> = BTreeSet::new();
+        for queue in self.change_queues.iter() {
+            for item in queue.heads.iter().flatten() {
+                if let Some(id) = item["id"].as_str() {
+                    ids.insert(id.to_string());
+                }
+                for reference in item["refs"].as_array().into_iter().flatten() {
+                    if let Some(id) = reference["id"].as_str() {
+                        ids.insert(id.to_string());
+                    }
+                }
+            }
+        }
+        ids.into_iter().collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusResult {
+    #[serde(default)]
+    pub pipelines: Vec<StatusPipeline>,
+    #[serde(flatten)]
+    pub other: Value,
+}
@@ -0,0 +1,15 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Projects {}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Project {
+    /// Project name
+    #[arg(required = true)]
+    pub name: String,
+
+    /// Only show jobs of this pipeline
+    #[arg(long, short)]
+    pub pipeline: Option<String>,
+}
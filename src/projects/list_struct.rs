@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectsResult {
+    pub name: String,
+    pub canonical_name: String,
+    pub connection_name: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+}
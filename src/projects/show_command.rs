@@ -0,0 +1,111 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::projects::show_parameters::Parameters;
+use crate::projects::show_struct::ProjectResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct ShowProject {
+    pub result: Option<ProjectResult>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl ShowProject {
+    pub fn new(config: Config) -> AnyhowResult<ShowProject> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters {
+                name: String::new(),
+                pipeline: None,
+            },
+        })
+    }
+
+    pub fn name(&mut self, name: String) -> AnyhowResult<&mut Self> {
+        self.parameters.name = name;
+        Ok(self)
+    }
+
+    pub fn pipeline(&mut self, pipeline: Option<String>) -> AnyhowResult<&mut Self> {
+        self.parameters.pipeline = pipeline;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/project/{}",
+            self.config.host, self.config.tenant, self.parameters.name
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let mut output: ProjectResult = serde_json::from_slice(&data)?;
+
+        // Pipeline filter
+        if let Some(pipeline) = &self.parameters.pipeline {
+            for config in output.configs.iter_mut() {
+                config.pipelines.retain(|p| &p.name == pipeline);
+            }
+        }
+
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let project = self.result.as_ref().unwrap();
+        println!("Project:    {}", project.name);
+        println!("Canonical:  {}", project.canonical_name);
+        println!("Connection: {}", project.connection_name);
+        println!("Type:       {}", project._type);
+
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Pipeline", "Job", "Branches", "Variant"]);
+
+        // A project can be configured in several places, one block each
+        for config in project.configs.iter() {
+            for pipeline in config.pipelines.iter() {
+                for variants in pipeline.jobs.iter() {
+                    for variant in variants.iter() {
+                        let branches = match variant.branches.is_empty() {
+                            true => "*".to_string(),
+                            false => variant.branches.join("\n"),
+                        };
+                        table.add_row(vec![
+                            Cell::new(pipeline.name.clone()),
+                            Cell::new(variant.name.clone()),
+                            Cell::new(branches),
+                            Cell::new(variant.variant_description.clone().unwrap_or_default()),
+                        ]);
+                    }
+                }
+            }
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobVariant {
+    pub name: String,
+    #[serde(default)]
+    pub branches: Vec<String>,
+    pub variant_description: Option<String>,
+    pub parent: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectPipeline {
+    pub name: String,
+    pub queue_name: Option<String>,
+    // One entry per job, holding every variant of it
+    #[serde(default)]
+    pub jobs: Vec<Vec<JobVariant>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    pub default_branch: Option<String>,
+    pub merge_mode: Option<String>,
+    #[serde(default)]
+    pub templates: Vec<String>,
+    #[serde(default)]
+    pub pipelines: Vec<ProjectPipeline>,
+    #[serde(flatten)]
+    pub other: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectResult {
+    pub name: String,
+    pub canonical_name: String,
+    pub connection_name: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(default)]
+    pub configs: Vec<ProjectConfig>,
+}
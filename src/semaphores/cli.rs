@@ -0,0 +1,4 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Semaphores {}
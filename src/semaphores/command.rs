@@ -0,0 +1,89 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::semaphores::semaphores_struct::SemaphoresResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct Semaphores {
+    pub result: Option<Vec<SemaphoresResult>>,
+    pub config: Config,
+}
+
+impl Semaphores {
+    pub fn new(config: Config) -> AnyhowResult<Semaphores> {
+        Ok(Self {
+            result: None,
+            config,
+        })
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!(
+            "https://{}/api/tenant/{}/semaphores",
+            self.config.host, self.config.tenant,
+        );
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<SemaphoresResult> = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Semaphore", "Held", "Global", "Holders"]);
+
+        for semaphore in self.result.as_ref().unwrap() {
+            let mut holders: Vec<String> = semaphore
+                .holders
+                .this_tenant
+                .iter()
+                .map(|holder| {
+                    format!(
+                        "{} ({})",
+                        holder.job_name.as_deref().unwrap_or("N/A"),
+                        holder.buildset_uuid.as_deref().unwrap_or("N/A")
+                    )
+                })
+                .collect();
+            if semaphore.holders.other_tenants > 0 {
+                holders.push(format!(
+                    "{} from other tenants",
+                    semaphore.holders.other_tenants
+                ));
+            }
+
+            table.add_row(vec![
+                Cell::new(semaphore.name.clone()),
+                Cell::new(format!("{}/{}", semaphore.holders.count, semaphore.max)),
+                Cell::new(semaphore.global),
+                Cell::new(holders.join("\n")),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
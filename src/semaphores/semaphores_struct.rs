@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SemaphoresResult {
+    pub name: String,
+    pub max: u64,
+    #[serde(default)]
+    pub global: bool,
+    #[serde(default)]
+    pub holders: Holders,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Holders {
+    #[serde(default)]
+    pub count: u64,
+    #[serde(default)]
+    pub this_tenant: Vec<Holder>,
+    // Global semaphores can be held from other tenants, only the count is known
+    #[serde(default)]
+    pub other_tenants: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Holder {
+    pub buildset_uuid: Option<String>,
+    pub job_name: Option<String>,
+}
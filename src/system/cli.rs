@@ -0,0 +1,11 @@
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Info {}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Components {
+    /// Exit with status 1 if a component is paused or missing
+    #[arg(long, short, default_value_t = false)]
+    pub check: bool,
+}
@@ -0,0 +1,124 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::system::components_parameters::Parameters;
+use crate::system::components_struct::ComponentsResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use serde_json::json;
+
+// Components a working Zuul cannot do without, mergers are optional as
+// executors also merge. zuul-web is not checked, it serves this API.
+const REQUIRED: [&str; 2] = ["scheduler", "executor"];
+
+#[derive(Debug)]
+pub struct Components {
+    pub result: Option<ComponentsResult>,
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Components {
+    pub fn new(config: Config) -> AnyhowResult<Components> {
+        Ok(Self {
+            result: None,
+            config,
+            parameters: Parameters { check: false },
+        })
+    }
+
+    pub fn check(&mut self, check: bool) -> AnyhowResult<&mut Self> {
+        self.parameters.check = check;
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!("https://{}/api/components", self.config.host);
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: ComponentsResult = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    /*
+     * Problems found by the check, a paused component or a required kind
+     * without any instance.
+     */
+    pub fn problems(&self) -> Vec<String> {
+        let components = self.result.as_ref().unwrap();
+        let mut problems: Vec<String> = REQUIRED
+            .iter()
+            .filter(|kind| components.get(**kind).is_none_or(|list| list.is_empty()))
+            .map(|kind| format!("No {kind} running"))
+            .collect();
+
+        for (kind, list) in components.iter() {
+            for component in list.iter().filter(|c| c.state == "paused") {
+                problems.push(format!("{kind} {} is paused", component.hostname));
+            }
+        }
+        problems
+    }
+
+    /*
+     * Used as exit status with --check
+     */
+    pub fn failed(&self) -> bool {
+        self.parameters.check && !self.problems().is_empty()
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        match self.parameters.check {
+            true => println!(
+                "{}",
+                serde_json::to_string(&json!({
+                    "components": self.result.as_ref().unwrap(),
+                    "problems": self.problems(),
+                }))?
+            ),
+            false => println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?),
+        }
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Component", "Hostname", "State", "Version"]);
+
+        for (kind, list) in self.result.as_ref().unwrap().iter() {
+            for component in list.iter() {
+                table.add_row(vec![
+                    Cell::new(kind.clone()),
+                    Cell::new(component.hostname.clone()),
+                    Cell::new(component.state.clone()),
+                    Cell::new(component.version.as_deref().unwrap_or("N/A")),
+                ]);
+            }
+        }
+        println!("{table}");
+
+        if self.parameters.check {
+            for problem in self.problems() {
+                eprintln!("{problem}");
+            }
+        }
+        Ok(())
+    }
+}
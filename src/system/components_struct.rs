@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Component kind, e.g. scheduler or executor, to the running instances
+pub type ComponentsResult = BTreeMap<String, Vec<Component>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Component {
+    pub hostname: String,
+    pub state: String,
+    pub version: Option<String>,
+}
@@ -0,0 +1,91 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::system::info_struct::InfoResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+
+#[derive(Debug)]
+pub struct Info {
+    pub result: Option<InfoResult>,
+    pub config: Config,
+}
+
+impl Info {
+    pub fn new(config: Config) -> AnyhowResult<Info> {
+        Ok(Self {
+            result: None,
+            config,
+        })
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!("https://{}/api/info", self.config.host);
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: InfoResult = serde_json::from_slice(&data)?;
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let info = &self.result.as_ref().unwrap().info;
+        let auth = &info.capabilities.auth;
+
+        let realms: Vec<String> = auth
+            .realms
+            .keys()
+            .map(|realm| match auth.default_realm.as_ref() == Some(realm) {
+                true => format!("{realm} (default)"),
+                false => realm.clone(),
+            })
+            .collect();
+
+        let stats = match &info.stats {
+            Some(stats) => format!(
+                "{} {}",
+                stats.url.as_deref().unwrap_or("N/A"),
+                stats.prefix.as_deref().unwrap_or("")
+            ),
+            None => "N/A".to_string(),
+        };
+
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Info", &self.config.host]);
+        table.add_row(vec![
+            "Websocket",
+            info.websocket_url.as_deref().unwrap_or("N/A"),
+        ]);
+        table.add_row(vec![
+            "Job history",
+            &info.capabilities.job_history.to_string(),
+        ]);
+        table.add_row(vec!["Auth realms", &realms.join("\n")]);
+        table.add_row(vec!["Read protected", &auth.read_protected.to_string()]);
+        table.add_row(vec!["Stats", stats.trim_end()]);
+        table.add_row(vec!["Tenant", info.tenant.as_deref().unwrap_or("N/A")]);
+        println!("{table}");
+        Ok(())
+    }
+}
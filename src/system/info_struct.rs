@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InfoResult {
+    pub info: Info,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Info {
+    #[serde(default)]
+    pub capabilities: Capabilities,
+    pub websocket_url: Option<String>,
+    pub stats: Option<Stats>,
+    pub tenant: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Capabilities {
+    #[serde(default)]
+    pub job_history: bool,
+    #[serde(default)]
+    pub auth: Auth,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Auth {
+    #[serde(default)]
+    pub realms: BTreeMap<String, serde_json::Value>,
+    pub default_realm: Option<String>,
+    #[serde(default)]
+    pub read_protected: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub url: Option<String>,
+    pub prefix: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+}
@@ -0,0 +1,104 @@
+use crate::config::Config;
+use crate::enums::output::Output;
+use crate::tenants::tenants_struct::TenantsResult;
+use crate::util::easy::send_receive;
+use anyhow::Result as AnyhowResult;
+use comfy_table::presets::UTF8_BORDERS_ONLY;
+use comfy_table::*;
+use log;
+use std::path::{Path, PathBuf};
+
+const CACHE_NAME: &str = "tenants.json";
+
+#[derive(Debug)]
+pub struct Tenants {
+    pub result: Option<Vec<TenantsResult>>,
+    pub config: Config,
+}
+
+impl Tenants {
+    pub fn new(config: Config) -> AnyhowResult<Tenants> {
+        Ok(Self {
+            result: None,
+            config,
+        })
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut data: Vec<u8> = Vec::new();
+        let url = format!("https://{}/api/tenants", self.config.host);
+
+        send_receive(&mut data, &url, &self.config.http);
+
+        let output: Vec<TenantsResult> = serde_json::from_slice(&data)?;
+
+        // Keep a copy for shell completion
+        log::debug!("Update cache: {CACHE_NAME}");
+        std::fs::write(Self::cache_path(&self.config.cache), &data)?;
+
+        self.result = Some(output);
+        Ok(self)
+    }
+
+    /*
+     * Tenant names from the last `sol tenants` run, empty if never run
+     */
+    pub fn cached_names(cache: &str) -> Vec<String> {
+        let Ok(fh) = std::fs::File::open(Self::cache_path(cache)) else {
+            return vec![];
+        };
+        let tenants: Vec<TenantsResult> = serde_json::from_reader(fh).unwrap_or_default();
+        tenants.into_iter().map(|tenant| tenant.name).collect()
+    }
+
+    /*
+     * Names of every tenant, used by the --all-tenants queries
+     */
+    pub fn names(config: &Config) -> AnyhowResult<Vec<String>> {
+        let mut tenants = Tenants::new(config.clone())?;
+        tenants.runner()?;
+        Ok(tenants
+            .result
+            .take()
+            .unwrap()
+            .into_iter()
+            .map(|tenant| tenant.name)
+            .collect())
+    }
+
+    fn cache_path(cache: &str) -> PathBuf {
+        Path::new(cache).join(CACHE_NAME)
+    }
+
+    pub fn show(&mut self) -> AnyhowResult<()> {
+        match self.config.output {
+            Output::JSON => self._show_json()?,
+            Output::USER => self._show_user()?,
+        }
+        Ok(())
+    }
+
+    fn _show_json(&mut self) -> AnyhowResult<()> {
+        println!("{}", serde_json::to_string(&self.result.as_ref().unwrap())?);
+        Ok(())
+    }
+
+    fn _show_user(&mut self) -> AnyhowResult<()> {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_BORDERS_ONLY)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_header(vec!["Tenant", "Projects", "Queue"]);
+
+        for tenant in self.result.as_ref().unwrap() {
+            table.add_row(vec![
+                Cell::new(tenant.name.clone()),
+                Cell::new(tenant.projects),
+                Cell::new(tenant.queue),
+            ]);
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TenantsResult {
+    pub name: String,
+    #[serde(default)]
+    pub projects: usize,
+    #[serde(default)]
+    pub queue: usize,
+}
@@ -0,0 +1,977 @@
+use crate::autohold::list_command::ListAutoHold;
+use crate::builds::command::Builds;
+use crate::buildsets::command::BuildSets;
+use crate::config::Config;
+use crate::functions::build_node::command::BuildNode;
+use crate::pipelines::queues_command::Queues;
+use crate::util::duration::format_seconds;
+use crate::util::easy::send_receive_optional;
+use crate::util::timestamp;
+use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, TimeDelta, Utc};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeSet;
+
+// Rows or lines moved by PageUp and PageDown
+const PAGE: i64 = 20;
+
+/*
+ * Top level panes, switched with Tab or their number
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pane {
+    Status,
+    BuildSets,
+    Builds,
+    Holds,
+}
+
+impl Pane {
+    pub const ALL: [Pane; 4] = [Pane::Status, Pane::BuildSets, Pane::Builds, Pane::Holds];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Pane::Status => "Status",
+            Pane::BuildSets => "Buildsets",
+            Pane::Builds => "Builds",
+            Pane::Holds => "Holds",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        Pane::ALL.iter().position(|pane| pane == self).unwrap()
+    }
+}
+
+/*
+ * What is shown, opened views are stacked on top of the pane
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum View {
+    Pane(Pane),
+    // Builds of a buildset
+    BuildSet {
+        uuid: String,
+    },
+    Nodes {
+        build: String,
+        log_url: Option<String>,
+    },
+    Logs {
+        build: String,
+        log_url: String,
+    },
+}
+
+impl View {
+    pub fn title(&self) -> String {
+        match self {
+            View::Pane(pane) => pane.title().to_string(),
+            View::BuildSet { uuid } => format!("buildset {uuid}"),
+            View::Nodes { build, .. } => format!("nodes of {build}"),
+            View::Logs { build, .. } => format!("logs of {build}"),
+        }
+    }
+}
+
+/*
+ * A table row, with the fields used to filter and navigate
+ */
+#[derive(Debug, Default, Clone)]
+pub struct Row {
+    pub cells: Vec<String>,
+    pub result: Option<String>,
+    pub pipeline: Option<String>,
+    pub project: Option<String>,
+    // Opened by Enter
+    pub open: Option<View>,
+    // Opened by l
+    pub logs: Option<View>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Filters {
+    pub result: Option<String>,
+    pub pipeline: Option<String>,
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Result,
+    Pipeline,
+    Project,
+}
+
+/*
+ * Content of a view, see `load`
+ */
+#[derive(Debug, Default)]
+pub struct Loaded {
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Row>,
+    pub lines: Vec<String>,
+}
+
+/*
+ * Selection, search and filters of a view, restored when going back to it
+ */
+#[derive(Debug, Default, Clone)]
+struct Saved {
+    selected: usize,
+    search: String,
+    filters: Filters,
+}
+
+/*
+ * State of the terminal UI. Keys only change the state, when `pending` is
+ * set the event loop loads the view on a worker thread, so the app can be
+ * driven and drawn without a terminal or a server.
+ */
+#[derive(Debug)]
+pub struct App {
+    pub config: Config,
+    pub pane: Pane,
+    stack: Vec<(View, Saved)>,
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Row>,
+    // Logs view
+    pub lines: Vec<String>,
+    pub selected: usize,
+    // Log lines scrolled up from the end
+    pub scroll: usize,
+    pub search: String,
+    // Search being typed
+    pub input: Option<String>,
+    pub filters: Filters,
+    pub message: Option<String>,
+    pub loaded_at: Option<DateTime<Utc>>,
+    pub pending: bool,
+    // A load is running
+    pub loading: bool,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new(config: Config) -> App {
+        App {
+            config,
+            pane: Pane::BuildSets,
+            stack: vec![],
+            header: vec![],
+            rows: vec![],
+            lines: vec![],
+            selected: 0,
+            scroll: 0,
+            search: String::new(),
+            input: None,
+            filters: Filters::default(),
+            message: None,
+            loaded_at: None,
+            pending: true,
+            loading: false,
+            quit: false,
+        }
+    }
+
+    pub fn view(&self) -> View {
+        match self.stack.last() {
+            Some((view, _)) => view.clone(),
+            None => View::Pane(self.pane),
+        }
+    }
+
+    // e.g. Buildsets › buildset 8f2c › nodes of 51ab
+    pub fn breadcrumb(&self) -> String {
+        let mut titles = vec![self.pane.title().to_string()];
+        titles.extend(self.stack.iter().map(|(view, _)| view.title()));
+        titles.join(" › ")
+    }
+
+    /*
+     * Rows matching the filters and the search
+     */
+    pub fn visible(&self) -> Vec<&Row> {
+        let search = self.search.to_lowercase();
+        let matches =
+            |filter: &Option<String>, value: &Option<String>| filter.is_none() || filter == value;
+        self.rows
+            .iter()
+            .filter(|row| {
+                matches(&self.filters.result, &row.result)
+                    && matches(&self.filters.pipeline, &row.pipeline)
+                    && matches(&self.filters.project, &row.project)
+                    && (search.is_empty()
+                        || row
+                            .cells
+                            .iter()
+                            .any(|cell| cell.to_lowercase().contains(&search)))
+            })
+            .collect()
+    }
+
+    /*
+     * Auto refresh is due, `refresh` in seconds, 0 disables it. Never while
+     * a load is running.
+     */
+    pub fn due(&self, refresh: i64) -> bool {
+        refresh > 0
+            && !self.loading
+            && self
+                .loaded_at
+                .is_some_and(|at| Utc::now() - at >= TimeDelta::seconds(refresh))
+    }
+
+    /*
+     * Take the pending load, the caller runs `load` with it off the UI
+     * thread and hands the result to `apply`
+     */
+    pub fn request(&mut self) -> Option<(Config, View)> {
+        if !self.pending {
+            return None;
+        }
+        self.pending = false;
+        self.loading = true;
+        self.message = Some("Loading...".to_string());
+        Some((self.config.clone(), self.view()))
+    }
+
+    /*
+     * Show what was loaded for `view`, errors are shown in the footer. A
+     * result for a view left in the meantime is dropped.
+     */
+    pub fn apply(&mut self, view: View, loaded: AnyhowResult<Loaded>) {
+        if view != self.view() {
+            return;
+        }
+        self.loading = false;
+        match loaded {
+            Ok(loaded) => {
+                self.header = loaded.header;
+                self.rows = loaded.rows;
+                self.lines = loaded.lines;
+                self.message = None;
+            }
+            Err(err) => {
+                self.rows.clear();
+                self.lines.clear();
+                self.message = Some(format!("Error: {err}"));
+            }
+        }
+        self.loaded_at = Some(Utc::now());
+        self.move_by(0);
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(input) = self.input.as_mut() {
+            match key.code {
+                KeyCode::Enter => {
+                    self.search = self.input.take().unwrap_or_default();
+                    self.selected = 0;
+                }
+                KeyCode::Esc => self.input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => (),
+            }
+            return;
+        }
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Tab => self.switch(Pane::ALL[(self.pane.index() + 1) % Pane::ALL.len()]),
+            KeyCode::BackTab => {
+                let count = Pane::ALL.len();
+                self.switch(Pane::ALL[(self.pane.index() + count - 1) % count])
+            }
+            KeyCode::Char(c @ '1'..='4') => self.switch(Pane::ALL[c as usize - '1' as usize]),
+            KeyCode::Up | KeyCode::Char('k') => self.move_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_by(1),
+            KeyCode::PageUp => self.move_by(-PAGE),
+            KeyCode::PageDown => self.move_by(PAGE),
+            KeyCode::Home | KeyCode::Char('g') => self.move_by(i64::MIN),
+            KeyCode::End | KeyCode::Char('G') => self.move_by(i64::MAX),
+            KeyCode::Enter => self.open(|row| row.open.clone(), "Nothing to open"),
+            KeyCode::Char('l') => self.open(|row| row.logs.clone(), "No logs"),
+            KeyCode::Esc | KeyCode::Backspace => self.back(),
+            KeyCode::Char('/') => self.input = Some(self.search.clone()),
+            KeyCode::Char('r') => self.cycle(Field::Result),
+            KeyCode::Char('p') => self.cycle(Field::Pipeline),
+            KeyCode::Char('P') => self.cycle(Field::Project),
+            KeyCode::Char('c') => {
+                self.filters = Filters::default();
+                self.search.clear();
+            }
+            KeyCode::Char('R') | KeyCode::F(5) => self.pending = true,
+            _ => (),
+        }
+    }
+
+    /*
+     * Move the selection, or scroll the logs, clamped to the content
+     */
+    fn move_by(&mut self, delta: i64) {
+        if let View::Logs { .. } = self.view() {
+            let last = self.lines.len().saturating_sub(1) as i64;
+            self.scroll = (self.scroll as i64).saturating_sub(delta).clamp(0, last) as usize;
+            return;
+        }
+        let last = self.visible().len().saturating_sub(1) as i64;
+        self.selected = (self.selected as i64).saturating_add(delta).clamp(0, last) as usize;
+    }
+
+    fn switch(&mut self, pane: Pane) {
+        if pane == self.pane && self.stack.is_empty() {
+            return;
+        }
+        self.pane = pane;
+        self.stack.clear();
+        self.restore(Saved::default());
+    }
+
+    fn open(&mut self, target: impl Fn(&Row) -> Option<View>, missing: &str) {
+        if let View::Logs { .. } = self.view() {
+            return;
+        }
+        let Some(view) = self
+            .visible()
+            .get(self.selected)
+            .and_then(|row| target(row))
+        else {
+            self.message = Some(missing.to_string());
+            return;
+        };
+        let saved = Saved {
+            selected: self.selected,
+            search: std::mem::take(&mut self.search),
+            filters: std::mem::take(&mut self.filters),
+        };
+        self.stack.push((view, saved));
+        self.restore(Saved::default());
+    }
+
+    fn back(&mut self) {
+        if let Some((_, saved)) = self.stack.pop() {
+            self.restore(saved);
+        }
+    }
+
+    fn restore(&mut self, saved: Saved) {
+        self.selected = saved.selected;
+        self.search = saved.search;
+        self.filters = saved.filters;
+        self.scroll = 0;
+        self.rows.clear();
+        self.lines.clear();
+        self.message = None;
+        self.pending = true;
+    }
+
+    /*
+     * Next value of the field found in the rows, then back to all
+     */
+    fn cycle(&mut self, field: Field) {
+        let get = |row: &Row| -> Option<String> {
+            match field {
+                Field::Result => row.result.clone(),
+                Field::Pipeline => row.pipeline.clone(),
+                Field::Project => row.project.clone(),
+            }
+        };
+        let values: BTreeSet<String> = self.rows.iter().filter_map(get).collect();
+        let filter = match field {
+            Field::Result => &mut self.filters.result,
+            Field::Pipeline => &mut self.filters.pipeline,
+            Field::Project => &mut self.filters.project,
+        };
+        *filter = match filter.as_ref() {
+            None => values.iter().next().cloned(),
+            Some(current) => values
+                .iter()
+                .skip_while(|value| *value != current)
+                .nth(1)
+                .cloned(),
+        };
+        self.selected = 0;
+    }
+}
+
+/*
+ * Fetch the content of a view. Blocking, the event loop calls it from a
+ * worker thread.
+ */
+pub fn load(config: Config, view: View) -> AnyhowResult<Loaded> {
+    match view {
+        View::Pane(Pane::Status) => load_status(config),
+        View::Pane(Pane::BuildSets) => load_buildsets(config),
+        View::Pane(Pane::Builds) => load_builds(config),
+        View::Pane(Pane::Holds) => load_holds(config),
+        View::BuildSet { uuid } => load_buildset(config, &uuid),
+        View::Nodes { build, log_url } => load_nodes(config, build, log_url),
+        View::Logs { log_url, .. } => load_logs(config, &log_url),
+    }
+}
+
+fn load_status(config: Config) -> AnyhowResult<Loaded> {
+    let mut queues = Queues::new(config)?;
+    queues.runner()?;
+
+    let header = vec!["Pipeline", "Queue", "Window", "Depth", "Projects"];
+    let rows = queues
+        .result
+        .iter()
+        .map(|queue| Row {
+            cells: vec![
+                queue.pipeline.clone(),
+                queue.name.clone(),
+                queue
+                    .window
+                    .map(|window| window.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+                queue.depth.to_string(),
+                queue.projects.join(" "),
+            ],
+            pipeline: Some(queue.pipeline.clone()),
+            ..Default::default()
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_buildsets(config: Config) -> AnyhowResult<Loaded> {
+    let mut buildsets = BuildSets::new(config)?;
+    buildsets.runner()?;
+
+    let now = Utc::now();
+    let header = vec![
+        "Result", "Pipeline", "Project", "Change", "Started", "Duration",
+    ];
+    let rows = buildsets
+        .result
+        .unwrap_or_default()
+        .iter()
+        .map(|buildset| {
+            let reference = buildset.refs.first();
+            let change = reference
+                .map(|reference| {
+                    let patchset = reference.patchset.as_deref().unwrap_or("?");
+                    format!("{}/{}", reference.change, patchset)
+                })
+                .unwrap_or_default();
+            let started = match buildset.first_build_start_time.as_ref() {
+                Some(start) => timestamp::relative(start, &now),
+                None => "Not started".to_string(),
+            };
+            Row {
+                cells: vec![
+                    na(buildset.result.as_ref()),
+                    buildset.pipeline.clone(),
+                    reference
+                        .map(|reference| reference.project.clone())
+                        .unwrap_or_default(),
+                    change,
+                    started,
+                    buildset
+                        .duration()
+                        .map(format_seconds)
+                        .unwrap_or_else(|| "N/A".to_string()),
+                ],
+                result: buildset.result.clone(),
+                pipeline: Some(buildset.pipeline.clone()),
+                project: reference.map(|reference| reference.project.clone()),
+                open: Some(View::BuildSet {
+                    uuid: buildset.uuid.clone(),
+                }),
+                logs: None,
+            }
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_builds(config: Config) -> AnyhowResult<Loaded> {
+    let mut builds = Builds::new(config)?;
+    builds.runner()?;
+
+    let now = Utc::now();
+    let header = vec![
+        "Result", "Job", "Pipeline", "Project", "Change", "Started", "Duration",
+    ];
+    let rows = builds
+        .result
+        .unwrap_or_default()
+        .iter()
+        .map(|build| Row {
+            cells: vec![
+                na(build.result.as_ref()),
+                build.job_name.clone(),
+                build.pipeline.clone(),
+                build._ref.project.clone(),
+                format!("{}/{}", build._ref.change, build._ref.patchset),
+                Builds::started(build, &now),
+                duration(build.duration),
+            ],
+            result: build.result.clone(),
+            pipeline: Some(build.pipeline.clone()),
+            project: Some(build._ref.project.clone()),
+            open: nodes(&build.uuid, build.log_url.as_ref()),
+            logs: logs(&build.uuid, build.log_url.as_ref()),
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_holds(config: Config) -> AnyhowResult<Loaded> {
+    let zone = config.zone()?;
+    let mut holds = ListAutoHold::new(config)?;
+    holds.runner()?.filter()?;
+
+    let header = vec!["Id", "Owner", "Project", "Job", "Held", "Expire"];
+    let rows = holds
+        .result
+        .unwrap_or_default()
+        .iter()
+        .map(|hold| Row {
+            cells: vec![
+                hold.id.clone(),
+                hold.owner.clone(),
+                hold.project.clone(),
+                hold.job.clone(),
+                format!("{}/{}", hold.current_count, hold.max_count),
+                zone.format_option(hold.expiry().as_ref()),
+            ],
+            project: Some(hold.project.clone()),
+            // Nodes of the first held build
+            open: hold.nodes.first().map(|held| View::Nodes {
+                build: held.build.clone(),
+                log_url: None,
+            }),
+            ..Default::default()
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_buildset(config: Config, uuid: &str) -> AnyhowResult<Loaded> {
+    let buildset = BuildSets::detail(&config, uuid)?;
+
+    let header = vec!["Result", "Job", "Duration"];
+    let rows = buildset
+        .builds
+        .iter()
+        .map(|build| Row {
+            cells: vec![
+                na(build.result.as_ref()),
+                build.job_name.clone(),
+                duration(build.duration),
+            ],
+            result: build.result.clone(),
+            pipeline: Some(buildset.pipeline.clone()),
+            open: nodes(&build.uuid, build.log_url.as_ref()),
+            logs: logs(&build.uuid, build.log_url.as_ref()),
+            ..Default::default()
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_nodes(config: Config, build: String, log_url: Option<String>) -> AnyhowResult<Loaded> {
+    let mut nodes = BuildNode::new(config)?;
+    nodes.build_id(build.clone())?.runner()?;
+
+    let header = vec!["Node", "IP addr", "Label", "Provider", "Region", "Groups"];
+    let rows = nodes
+        .result
+        .iter()
+        .map(|node| Row {
+            cells: vec![
+                node.name.clone(),
+                na(node.ip.as_ref()),
+                node.label.clone(),
+                na(node.nodepool.provider.as_ref()),
+                na(node.nodepool.region.as_ref()),
+                node.groups.join(" "),
+            ],
+            logs: logs(&build, log_url.as_ref()),
+            ..Default::default()
+        })
+        .collect();
+    Ok(Loaded {
+        header,
+        rows,
+        ..Default::default()
+    })
+}
+
+fn load_logs(config: Config, log_url: &str) -> AnyhowResult<Loaded> {
+    let mut data: Vec<u8> = vec![];
+    let url = format!("{log_url}job-output.txt");
+    if !send_receive_optional(&mut data, &url, &config.http)? {
+        anyhow::bail!("No job-output.txt in {log_url}");
+    }
+    let lines = String::from_utf8_lossy(&data)
+        .lines()
+        .map(|line| line.to_string())
+        .collect();
+    Ok(Loaded {
+        lines,
+        ..Default::default()
+    })
+}
+
+fn na(value: Option<&String>) -> String {
+    value.cloned().unwrap_or_else(|| "N/A".to_string())
+}
+
+fn duration(seconds: Option<f64>) -> String {
+    match seconds {
+        Some(seconds) => format_seconds(seconds),
+        None => "N/A".to_string(),
+    }
+}
+
+// The inventory listing the nodes is in the logs
+fn nodes(build: &str, log_url: Option<&String>) -> Option<View> {
+    log_url.map(|log_url| View::Nodes {
+        build: build.to_string(),
+        log_url: Some(log_url.clone()),
+    })
+}
+
+fn logs(build: &str, log_url: Option<&String>) -> Option<View> {
+    log_url.map(|log_url| View::Logs {
+        build: build.to_string(),
+        log_url: log_url.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::ui;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn press(app: &mut App, codes: &[KeyCode]) {
+        for code in codes {
+            app.handle_key(KeyEvent::from(*code));
+        }
+    }
+
+    fn typed(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    // Answer the pending load the way the worker thread would
+    fn serve(app: &mut App, header: Vec<&'static str>, rows: Vec<Row>) -> View {
+        let (_, view) = app.request().expect("a pending load");
+        app.apply(
+            view.clone(),
+            Ok(Loaded {
+                header,
+                rows,
+                ..Default::default()
+            }),
+        );
+        view
+    }
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 12)).unwrap();
+        terminal.draw(|frame| ui::draw(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .map(|line| line.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn buildset(uuid: &str, result: &str, pipeline: &str, project: &str) -> Row {
+        Row {
+            cells: vec![
+                result.to_string(),
+                pipeline.to_string(),
+                project.to_string(),
+            ],
+            result: Some(result.to_string()),
+            pipeline: Some(pipeline.to_string()),
+            project: Some(project.to_string()),
+            open: Some(View::BuildSet {
+                uuid: uuid.to_string(),
+            }),
+            logs: None,
+        }
+    }
+
+    fn buildsets() -> Vec<Row> {
+        vec![
+            buildset("bs1", "SUCCESS", "check", "org/alpha"),
+            buildset("bs2", "FAILURE", "gate", "org/beta"),
+            buildset("bs3", "FAILURE", "check", "org/alpha"),
+        ]
+    }
+
+    const HEADER: [&str; 3] = ["Result", "Pipeline", "Project"];
+
+    #[test]
+    fn panes() {
+        let mut app = App::new(Config::default());
+        assert_eq!(serve(&mut app, vec![], vec![]), View::Pane(Pane::BuildSets));
+
+        press(&mut app, &[KeyCode::Tab]);
+        assert_eq!(app.view(), View::Pane(Pane::Builds));
+        assert!(app.pending);
+        serve(&mut app, vec!["Job"], vec![]);
+        assert!(screen(&app).contains("┌ Builds (0/0) "));
+
+        press(&mut app, &[KeyCode::Char('4')]);
+        assert_eq!(app.view(), View::Pane(Pane::Holds));
+        press(&mut app, &[KeyCode::Tab]);
+        assert_eq!(app.view(), View::Pane(Pane::Status));
+        press(&mut app, &[KeyCode::BackTab]);
+        assert_eq!(app.view(), View::Pane(Pane::Holds));
+        serve(&mut app, vec![], vec![]);
+
+        // Already there, nothing to reload
+        press(&mut app, &[KeyCode::Char('4')]);
+        assert!(!app.pending);
+        press(&mut app, &[KeyCode::Char('q')]);
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn open_and_back() {
+        let mut app = App::new(Config::default());
+        serve(&mut app, HEADER.to_vec(), buildsets());
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]);
+        assert_eq!(
+            app.view(),
+            View::BuildSet {
+                uuid: "bs2".to_string()
+            }
+        );
+        assert!(app.rows.is_empty());
+
+        let build = Row {
+            cells: vec!["FAILURE".to_string(), "unit".to_string()],
+            result: Some("FAILURE".to_string()),
+            open: nodes("b1", Some(&"https://logs/b1/".to_string())),
+            logs: logs("b1", Some(&"https://logs/b1/".to_string())),
+            ..Default::default()
+        };
+        serve(&mut app, vec!["Result", "Job"], vec![build]);
+        press(&mut app, &[KeyCode::Enter]);
+        let node = Row {
+            cells: vec!["controller".to_string(), "10.0.0.1".to_string()],
+            ..Default::default()
+        };
+        serve(&mut app, vec!["Node", "IP addr"], vec![node]);
+        let text = screen(&app);
+        assert!(text.contains("Buildsets › buildset bs2 › nodes of b1 (1/1)"));
+        assert!(text.contains("controller"));
+
+        // Nodes cannot be opened further
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.message.as_deref(), Some("Nothing to open"));
+
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(
+            app.view(),
+            View::BuildSet {
+                uuid: "bs2".to_string()
+            }
+        );
+        press(&mut app, &[KeyCode::Backspace]);
+        assert_eq!(app.view(), View::Pane(Pane::BuildSets));
+        serve(&mut app, HEADER.to_vec(), buildsets());
+        assert_eq!(app.selected, 1);
+        assert!(screen(&app).contains("┌ Buildsets (3/3) "));
+    }
+
+    #[test]
+    fn logs_view() {
+        let mut app = App::new(Config::default());
+        press(&mut app, &[KeyCode::Char('3')]);
+        let build = Row {
+            cells: vec!["SUCCESS".to_string(), "unit".to_string()],
+            logs: logs("b1", Some(&"https://logs/b1/".to_string())),
+            ..Default::default()
+        };
+        serve(&mut app, vec!["Result", "Job"], vec![build]);
+        press(&mut app, &[KeyCode::Char('l')]);
+        let (_, view) = app.request().unwrap();
+        assert_eq!(
+            view,
+            View::Logs {
+                build: "b1".to_string(),
+                log_url: "https://logs/b1/".to_string()
+            }
+        );
+        let lines = (1..=30).map(|n| format!("line {n}")).collect();
+        app.apply(
+            view,
+            Ok(Loaded {
+                lines,
+                ..Default::default()
+            }),
+        );
+
+        // The tail is shown, 8 lines fit between the borders
+        let text = screen(&app);
+        assert!(text.contains("Builds › logs of b1 (23-30/30)"));
+        assert!(text.contains("line 30"));
+        assert!(!text.contains("line 22"));
+        press(&mut app, &[KeyCode::Up, KeyCode::Up]);
+        assert!(screen(&app).contains("(21-28/30)"));
+        press(&mut app, &[KeyCode::Home]);
+        assert!(screen(&app).contains("(1-8/30)"));
+
+        // Logs open nothing further
+        press(&mut app, &[KeyCode::Char('l')]);
+        assert!(!app.pending);
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view(), View::Pane(Pane::Builds));
+    }
+
+    #[test]
+    fn stale_result_dropped() {
+        let mut app = App::new(Config::default());
+        let (_, first) = app.request().unwrap();
+        press(&mut app, &[KeyCode::Tab]);
+        app.apply(
+            first,
+            Ok(Loaded {
+                header: HEADER.to_vec(),
+                rows: buildsets(),
+                ..Default::default()
+            }),
+        );
+        assert!(app.rows.is_empty());
+        assert!(app.pending);
+
+        serve(&mut app, vec![], vec![]);
+        assert!(!app.loading);
+        assert!(app.message.is_none());
+    }
+
+    #[test]
+    fn error_shown() {
+        let mut app = App::new(Config::default());
+        let (_, view) = app.request().unwrap();
+        assert_eq!(app.message.as_deref(), Some("Loading..."));
+        assert!(!app.due(1));
+        app.apply(view, Err(anyhow::anyhow!("GET https://zuul failed")));
+        assert!(screen(&app).contains("Error: GET https://zuul failed"));
+    }
+
+    #[test]
+    fn search() {
+        let mut app = App::new(Config::default());
+        serve(&mut app, HEADER.to_vec(), buildsets());
+
+        press(&mut app, &[KeyCode::Char('/')]);
+        typed(&mut app, "BETAx");
+        press(&mut app, &[KeyCode::Backspace]);
+        assert!(screen(&app).contains("/BETA"));
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.search, "BETA");
+        let text = screen(&app);
+        assert!(text.contains("Buildsets (1/3)"));
+        assert!(text.contains("org/beta"));
+        assert!(!text.contains("org/alpha"));
+
+        // Esc drops what is typed and keeps the search
+        press(&mut app, &[KeyCode::Char('/')]);
+        typed(&mut app, "gamma");
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.search, "BETA");
+        assert_eq!(app.visible().len(), 1);
+
+        press(&mut app, &[KeyCode::Char('c')]);
+        assert_eq!(app.visible().len(), 3);
+    }
+
+    #[test]
+    fn filters() {
+        let mut app = App::new(Config::default());
+        serve(&mut app, HEADER.to_vec(), buildsets());
+
+        press(&mut app, &[KeyCode::Char('r')]);
+        assert_eq!(app.filters.result.as_deref(), Some("FAILURE"));
+        assert_eq!(app.visible().len(), 2);
+        press(&mut app, &[KeyCode::Char('p')]);
+        assert_eq!(app.filters.pipeline.as_deref(), Some("check"));
+        assert_eq!(app.visible().len(), 1);
+        assert!(screen(&app).contains("result=FAILURE · pipeline=check"));
+
+        press(&mut app, &[KeyCode::Char('p'), KeyCode::Char('p')]);
+        assert_eq!(app.filters.pipeline, None);
+        press(&mut app, &[KeyCode::Char('r')]);
+        assert_eq!(app.filters.result.as_deref(), Some("SUCCESS"));
+        press(&mut app, &[KeyCode::Char('r')]);
+        assert_eq!(app.filters.result, None);
+
+        press(&mut app, &[KeyCode::Char('P'), KeyCode::Char('P')]);
+        assert_eq!(app.filters.project.as_deref(), Some("org/beta"));
+        assert_eq!(app.visible().len(), 1);
+        press(&mut app, &[KeyCode::Char('c')]);
+        assert_eq!(app.filters, Filters::default());
+    }
+
+    #[test]
+    fn back_restores_search_and_filters() {
+        let mut app = App::new(Config::default());
+        serve(&mut app, HEADER.to_vec(), buildsets());
+        press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('/')]);
+        typed(&mut app, "alpha");
+        press(&mut app, &[KeyCode::Enter, KeyCode::Enter]);
+        assert_eq!(
+            app.view(),
+            View::BuildSet {
+                uuid: "bs3".to_string()
+            }
+        );
+        assert_eq!(app.filters, Filters::default());
+        assert!(app.search.is_empty());
+        serve(&mut app, vec![], vec![]);
+
+        press(&mut app, &[KeyCode::Esc]);
+        serve(&mut app, HEADER.to_vec(), buildsets());
+        assert_eq!(app.filters.result.as_deref(), Some("FAILURE"));
+        assert_eq!(app.search, "alpha");
+        let text = screen(&app);
+        assert!(text.contains("Buildsets (1/3)"));
+        assert!(text.contains("result=FAILURE · /alpha"));
+    }
+}
@@ -0,0 +1,10 @@
+use crate::util::duration::parse_duration;
+use chrono::TimeDelta;
+use clap::Parser;
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct Tui {
+    /// Auto refresh interval, 0 disables it
+    #[arg(long, short, default_value = "30s", value_parser = parse_duration)]
+    pub refresh: TimeDelta,
+}
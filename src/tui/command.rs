@@ -0,0 +1,86 @@
+use crate::config::Config;
+use crate::tui::app::{self, App, Loaded, View};
+use crate::tui::parameters::Parameters;
+use crate::tui::ui;
+use anyhow::Result as AnyhowResult;
+use chrono::TimeDelta;
+use log;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::Terminal;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// How long to wait for a key before redrawing
+const POLL: Duration = Duration::from_millis(250);
+
+#[derive(Debug)]
+pub struct Tui {
+    pub config: Config,
+    pub parameters: Parameters,
+}
+
+impl Tui {
+    pub fn new(config: Config) -> AnyhowResult<Tui> {
+        Ok(Self {
+            config,
+            parameters: Parameters { refresh: 30 },
+        })
+    }
+
+    pub fn refresh(&mut self, refresh: TimeDelta) -> AnyhowResult<&mut Self> {
+        self.parameters.refresh = refresh.num_seconds();
+        Ok(self)
+    }
+
+    pub fn runner(&mut self) -> AnyhowResult<&mut Self> {
+        log::debug!("{self:#?}");
+        let mut app = App::new(self.config.clone());
+
+        // Restores the terminal on panic too
+        let mut terminal = ratatui::init();
+        let result = self.event_loop(&mut terminal, &mut app);
+        ratatui::restore();
+
+        result?;
+        Ok(self)
+    }
+
+    fn event_loop<B: Backend>(
+        &self,
+        terminal: &mut Terminal<B>,
+        app: &mut App,
+    ) -> AnyhowResult<()> {
+        // Loads run on worker threads so keys are handled meanwhile
+        let (sender, receiver) = mpsc::channel::<(View, AnyhowResult<Loaded>)>();
+        loop {
+            if let Some((config, view)) = app.request() {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    let loaded = app::load(config, view.clone());
+                    // The receiver is gone once the UI quit
+                    let _ = sender.send((view, loaded));
+                });
+            }
+            while let Ok((view, loaded)) = receiver.try_recv() {
+                app.apply(view, loaded);
+            }
+            terminal.draw(|frame| ui::draw(frame, app))?;
+            if app.quit {
+                return Ok(());
+            }
+
+            if event::poll(POLL)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        app.handle_key(key);
+                    }
+                }
+            }
+            if app.due(self.parameters.refresh) {
+                app.pending = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::tui::app::{App, Pane, View};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Tabs};
+use ratatui::Frame;
+
+const HELP: &str =
+    "q quit · tab pane · ↵ open · l logs · esc back · / search · r p P filter · c clear · R refresh";
+
+// Widest a column grows before the table wraps the rest
+const MAX_WIDTH: usize = 60;
+
+/*
+ * Draw the screen from the app state only, so it renders the same on a
+ * terminal or on ratatui's TestBackend
+ */
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [tabs, main, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    draw_tabs(frame, app, tabs);
+    match app.view() {
+        View::Logs { .. } => draw_logs(frame, app, main),
+        _ => draw_table(frame, app, main),
+    }
+    draw_footer(frame, app, footer);
+}
+
+fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
+    let titles = Pane::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, pane)| format!("{} {}", index + 1, pane.title()));
+    let tabs = Tabs::new(titles)
+        .select(app.pane.index())
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_widget(tabs, area);
+}
+
+fn draw_table(frame: &mut Frame, app: &App, area: Rect) {
+    let rows = app.visible();
+
+    // Columns as wide as their content
+    let widths: Vec<Constraint> = app
+        .header
+        .iter()
+        .enumerate()
+        .map(|(index, header)| {
+            let width = rows
+                .iter()
+                .filter_map(|row| row.cells.get(index))
+                .map(|cell| cell.chars().count())
+                .chain([header.chars().count()])
+                .max()
+                .unwrap_or(0);
+            Constraint::Length(width.min(MAX_WIDTH) as u16)
+        })
+        .collect();
+
+    let header = Row::new(app.header.iter().map(|header| Cell::from(*header)))
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let body = rows.iter().map(|row| {
+        Row::new(row.cells.iter().map(|cell| Cell::from(cell.as_str())))
+            .style(Style::default().fg(color(row.result.as_deref())))
+    });
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        " {} ({}/{}) ",
+        app.breadcrumb(),
+        rows.len(),
+        app.rows.len()
+    ));
+    let table = Table::new(body, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut state = TableState::default().with_selected((!rows.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+/*
+ * Log lines ending `scroll` lines above the last one, the tail by default
+ */
+fn draw_logs(frame: &mut Frame, app: &App, area: Rect) {
+    let height = area.height.saturating_sub(2) as usize;
+    let total = app.lines.len();
+    let end = (total - app.scroll.min(total)).max(height.min(total));
+    let start = end.saturating_sub(height);
+
+    let lines: Vec<Line> = app.lines[start..end]
+        .iter()
+        .map(|line| Line::raw(line.as_str()))
+        .collect();
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        " {} ({}-{}/{}) ",
+        app.breadcrumb(),
+        start + 1,
+        end,
+        total
+    ));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    if let Some(input) = app.input.as_ref() {
+        frame.render_widget(Paragraph::new(format!("/{input}")), area);
+        frame.set_cursor_position((area.x + 1 + input.chars().count() as u16, area.y));
+        return;
+    }
+
+    let mut status: Vec<String> = vec![];
+    if let Some(message) = app.message.as_ref() {
+        status.push(message.clone());
+    }
+    for (name, filter) in [
+        ("result", &app.filters.result),
+        ("pipeline", &app.filters.pipeline),
+        ("project", &app.filters.project),
+    ] {
+        if let Some(value) = filter {
+            status.push(format!("{name}={value}"));
+        }
+    }
+    if !app.search.is_empty() {
+        status.push(format!("/{}", app.search));
+    }
+    if let (Some(loaded_at), Ok(zone)) = (app.loaded_at.as_ref(), app.config.zone()) {
+        status.push(format!("updated {}", zone.format(loaded_at)));
+    }
+    status.push(HELP.to_string());
+    frame.render_widget(
+        Paragraph::new(status.join(" · ")).style(Style::default().fg(Color::DarkGray)),
+        area,
+    );
+}
+
+fn color(result: Option<&str>) -> Color {
+    match result {
+        Some("SUCCESS") => Color::Green,
+        Some("SKIPPED") => Color::DarkGray,
+        Some(_) => Color::Red,
+        None => Color::Reset,
+    }
+}
@@ -0,0 +1,213 @@
+use anyhow::Result as AnyhowResult;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+lazy_static! {
+    // 12345, 12345,3 or 12345/3
+    static ref BARE: Regex = Regex::new(r"^(\d+)(?:[,/](\w+))?$").unwrap();
+    // https://review.example.org/c/org/project/+/12345/3
+    static ref GERRIT: Regex =
+        Regex::new(r"^https?://[^/]+/(?:.*/)?c/(.+)/\+/(\d+)(?:/(\d+))?(?:/.*)?$").unwrap();
+    // https://review.example.org/#/c/12345/3 or https://review.example.org/12345,
+    // any other path is not a change
+    static ref GERRIT_SHORT: Regex =
+        Regex::new(r"^https?://[^/]+/(?:(?:[^#]*/)?#/c/)?(\d+)(?:/(\d+))?/?$").unwrap();
+    // https://github.com/org/project/pull/123/commits/<sha>
+    static ref GITHUB: Regex =
+        Regex::new(r"^https?://[^/]+/([^/]+/[^/]+)/pull/(\d+)(?:/commits/([0-9a-f]+))?(?:/.*)?$")
+            .unwrap();
+    // https://gitlab.com/group/sub/project/-/merge_requests/45
+    static ref GITLAB: Regex =
+        Regex::new(r"^https?://[^/]+/(.+)/-/merge_requests/(\d+)(?:/.*)?$").unwrap();
+}
+
+/*
+ * A change as given on the command line: a number, Zuul's `change,patchset`
+ * notation or the URL of a Gerrit change, GitHub pull request or GitLab
+ * merge request
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeRef {
+    pub project: Option<String>,
+    pub change: String,
+    pub patchset: Option<String>,
+}
+
+impl ChangeRef {
+    /*
+     * Usable as clap value_parser
+     */
+    pub fn parse(value: &str) -> AnyhowResult<ChangeRef> {
+        let value = value.trim();
+        let group = |captures: &regex::Captures, index: usize| {
+            captures.get(index).map(|m| m.as_str().to_string())
+        };
+
+        if let Some(captures) = BARE.captures(value) {
+            return Ok(ChangeRef {
+                project: None,
+                change: captures[1].to_string(),
+                patchset: group(&captures, 2),
+            });
+        }
+
+        for (regex, has_project) in [
+            (&*GITLAB, true),
+            (&*GITHUB, true),
+            (&*GERRIT, true),
+            (&*GERRIT_SHORT, false),
+        ] {
+            let Some(captures) = regex.captures(value) else {
+                continue;
+            };
+            let change = match has_project {
+                true => ChangeRef {
+                    project: group(&captures, 1),
+                    change: captures[2].to_string(),
+                    patchset: group(&captures, 3),
+                },
+                false => ChangeRef {
+                    project: None,
+                    change: captures[1].to_string(),
+                    patchset: group(&captures, 2),
+                },
+            };
+            return Ok(change);
+        }
+
+        anyhow::bail!("Invalid change: {value}, expected a number, change,patchset or a review URL")
+    }
+}
+
+impl fmt::Display for ChangeRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.patchset {
+            Some(patchset) => write!(f, "{},{}", self.change, patchset),
+            None => write!(f, "{}", self.change),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(project: Option<&str>, change: &str, patchset: Option<&str>) -> ChangeRef {
+        ChangeRef {
+            project: project.map(|project| project.to_string()),
+            change: change.to_string(),
+            patchset: patchset.map(|patchset| patchset.to_string()),
+        }
+    }
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            ChangeRef::parse("12345").unwrap(),
+            change(None, "12345", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("12345,3").unwrap(),
+            change(None, "12345", Some("3"))
+        );
+        assert_eq!(
+            ChangeRef::parse(" 12345/3 ").unwrap(),
+            change(None, "12345", Some("3"))
+        );
+    }
+
+    #[test]
+    fn gerrit() {
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/c/org/project/+/12345/3").unwrap(),
+            change(Some("org/project"), "12345", Some("3"))
+        );
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/c/org/project/+/12345").unwrap(),
+            change(Some("org/project"), "12345", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/c/org/project/+/12345/3/src/main.rs")
+                .unwrap(),
+            change(Some("org/project"), "12345", Some("3"))
+        );
+        // Gerrit served under a path prefix
+        assert_eq!(
+            ChangeRef::parse("https://example.org/gerrit/c/org/project/+/12345").unwrap(),
+            change(Some("org/project"), "12345", None)
+        );
+    }
+
+    #[test]
+    fn gerrit_short() {
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/#/c/12345/3").unwrap(),
+            change(None, "12345", Some("3"))
+        );
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/12345").unwrap(),
+            change(None, "12345", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("https://review.example.org/12345/").unwrap(),
+            change(None, "12345", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("https://example.org/r/#/c/12345").unwrap(),
+            change(None, "12345", None)
+        );
+    }
+
+    #[test]
+    fn github() {
+        assert_eq!(
+            ChangeRef::parse("https://github.com/org/project/pull/123").unwrap(),
+            change(Some("org/project"), "123", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("https://github.com/org/project/pull/123/commits/0a1b2c").unwrap(),
+            change(Some("org/project"), "123", Some("0a1b2c"))
+        );
+        assert_eq!(
+            ChangeRef::parse("https://github.com/org/project/pull/123/files").unwrap(),
+            change(Some("org/project"), "123", None)
+        );
+    }
+
+    #[test]
+    fn gitlab() {
+        assert_eq!(
+            ChangeRef::parse("https://gitlab.com/group/project/-/merge_requests/45").unwrap(),
+            change(Some("group/project"), "45", None)
+        );
+        assert_eq!(
+            ChangeRef::parse("https://gitlab.com/group/sub/project/-/merge_requests/45/diffs")
+                .unwrap(),
+            change(Some("group/sub/project"), "45", None)
+        );
+    }
+
+    #[test]
+    fn rejects() {
+        for value in [
+            "",
+            "abc",
+            "12345,",
+            "12345,3,1",
+            "-12345",
+            "https://review.example.org/",
+            "https://github.com/org/project/issues/123",
+            "https://gitlab.com/group/project/-/issues/45",
+        ] {
+            assert!(ChangeRef::parse(value).is_err(), "{value} accepted");
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(change(None, "12345", Some("3")).to_string(), "12345,3");
+        assert_eq!(change(Some("org/a"), "12345", None).to_string(), "12345");
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::{DateTime, Utc};
 use num_integer::div_rem;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -46,10 +46,8 @@ impl fmt::Display for DiffDateTimeNow {
 }
 
 impl DiffDateTimeNow {
-    pub fn new(rfc3339_string: String) -> Self {
-        let created_at = DateTime::parse_from_rfc3339(&rfc3339_string).unwrap();
-        let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
-        let tmp = (now - created_at).num_seconds();
+    pub fn new(created_at: &DateTime<Utc>) -> Self {
+        let tmp = (Utc::now() - *created_at).num_seconds();
         let secs = tmp;
 
         let (years, remainder) = div_rem(secs, SECS_IN_YEAR);
@@ -0,0 +1,125 @@
+use anyhow::Result as AnyhowResult;
+use chrono::TimeDelta;
+
+/*
+ * Parse a human duration like `90s`, `30m`, `2h`, `1d`, `1w` or combined
+ * `1h30m`. A bare number is seconds. Negative or out of range durations are
+ * errors.
+ */
+pub fn parse_duration(value: &str) -> AnyhowResult<TimeDelta> {
+    let value = value.trim();
+    if value.is_empty() {
+        anyhow::bail!("Empty duration");
+    }
+    let too_large = || anyhow::anyhow!("Duration too large: {value}");
+    if value.chars().all(|c| c.is_ascii_digit()) {
+        let secs = value.parse::<i64>().map_err(|_| too_large())?;
+        return TimeDelta::try_seconds(secs).ok_or_else(too_large);
+    }
+
+    let mut total = TimeDelta::zero();
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        if number.is_empty() {
+            anyhow::bail!("Invalid duration: {value}");
+        }
+        let amount = number.parse::<i64>().map_err(|_| too_large())?;
+        let part = match c {
+            's' => TimeDelta::try_seconds(amount),
+            'm' => TimeDelta::try_minutes(amount),
+            'h' => TimeDelta::try_hours(amount),
+            'd' => TimeDelta::try_days(amount),
+            'w' => TimeDelta::try_weeks(amount),
+            _ => anyhow::bail!("Invalid duration unit: {c} in {value}"),
+        };
+        total = part
+            .and_then(|part| total.checked_add(&part))
+            .ok_or_else(too_large)?;
+        number.clear();
+    }
+    if !number.is_empty() {
+        anyhow::bail!("Missing unit in duration: {value}");
+    }
+    Ok(total)
+}
+
+/*
+ * Render seconds as `1h 02m 13s`, leading zero units are left out
+ */
+pub fn format_seconds(seconds: f64) -> String {
+    let total = seconds.round() as i64;
+    let (hours, minutes, secs) = (total / 3600, total % 3600 / 60, total % 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{secs}s"),
+        (0, _) => format!("{minutes}m {secs:02}s"),
+        _ => format!("{hours}h {minutes:02}m {secs:02}s"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_units() {
+        assert_eq!(parse_duration("90").unwrap(), TimeDelta::seconds(90));
+        assert_eq!(parse_duration("90s").unwrap(), TimeDelta::seconds(90));
+        assert_eq!(parse_duration("30m").unwrap(), TimeDelta::minutes(30));
+        assert_eq!(parse_duration("2h").unwrap(), TimeDelta::hours(2));
+        assert_eq!(parse_duration(" 1d ").unwrap(), TimeDelta::days(1));
+        assert_eq!(parse_duration("1w").unwrap(), TimeDelta::weeks(1));
+    }
+
+    #[test]
+    fn parse_combined() {
+        assert_eq!(parse_duration("1h30m").unwrap(), TimeDelta::minutes(90));
+        assert_eq!(
+            parse_duration("1d2h3m4s").unwrap(),
+            TimeDelta::seconds(93784)
+        );
+    }
+
+    #[test]
+    fn parse_rejects() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("2y").is_err());
+        assert!(parse_duration("1h30").is_err());
+        assert!(parse_duration("-1h").is_err());
+        assert!(parse_duration("2026-10-01").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_negative() {
+        assert!(parse_duration("-5").is_err());
+        assert!(parse_duration("+5").is_err());
+        assert!(parse_duration("-1h").is_err());
+        assert!(parse_duration("1h-5m").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_out_of_range() {
+        assert!(parse_duration("99999999999999999w").is_err());
+        assert!(parse_duration("99999999999999999999").is_err());
+        assert!(parse_duration("9223372036854775807").is_err());
+        assert!(parse_duration("99999999999999999999s").is_err());
+        // Each part fits, the sum does not
+        assert!(parse_duration("10000000000w").is_ok());
+        assert!(parse_duration("10000000000w10000000000w").is_err());
+        assert_eq!(
+            parse_duration("9223372036854775").unwrap(),
+            TimeDelta::seconds(9223372036854775)
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_seconds(0.0), "0s");
+        assert_eq!(format_seconds(59.6), "1m 00s");
+        assert_eq!(format_seconds(3733.0), "1h 02m 13s");
+    }
+}
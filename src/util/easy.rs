@@ -1,8 +1,17 @@
-use curl::easy::Easy;
+use crate::config::Http;
+use anyhow::Result as AnyhowResult;
+use curl::easy::{Easy2, Handler, List, WriteError};
+use curl::multi::{Easy2Handle, Multi};
 #[allow(unused_imports)]
 use log;
 use serde_derive::Deserialize;
+use std::collections::{HashMap, VecDeque};
 use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// First retry delay, doubled on every further attempt
+const BACKOFF_BASE_MS: u64 = 500;
 
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
@@ -11,32 +20,307 @@ pub struct ErrorResponse {
     pub other: serde_json::Value,
 }
 
-pub fn send_receive(data: &mut Vec<u8>, url: &str) {
+/*
+ * Collects the response body of a transfer
+ */
+struct Collector(Vec<u8>);
+
+impl Handler for Collector {
+    fn write(&mut self, data: &[u8]) -> Result<usize, WriteError> {
+        self.0.extend_from_slice(data);
+        Ok(data.len())
+    }
+}
+
+/*
+ * Create a handle for url with the transport settings applied
+ */
+fn new_easy(url: &str, http: &Http) -> Result<Easy2<Collector>, curl::Error> {
+    let mut easy = Easy2::new(Collector(Vec::new()));
+    easy.url(url)?;
+
+    if let Some(secs) = http.connect_timeout {
+        easy.connect_timeout(Duration::from_secs(secs))?;
+    }
+    if let Some(secs) = http.timeout {
+        easy.timeout(Duration::from_secs(secs))?;
+    }
+    if let Some(proxy) = http.proxy.as_ref() {
+        easy.proxy(proxy)?;
+    }
+    if let Some(ca_bundle) = http.ca_bundle.as_ref() {
+        easy.cainfo(ca_bundle)?;
+    }
+    if let Some(cert) = http.client_cert.as_ref() {
+        easy.ssl_cert(cert)?;
+    }
+    if let Some(key) = http.client_key.as_ref() {
+        easy.ssl_key(key)?;
+    }
+    if http.insecure {
+        easy.ssl_verify_peer(false)?;
+        easy.ssl_verify_host(false)?;
+    }
+    Ok(easy)
+}
+
+fn retryable_error(err: &curl::Error) -> bool {
+    err.is_couldnt_connect()
+        || err.is_operation_timedout()
+        || err.is_send_error()
+        || err.is_recv_error()
+        || err.is_got_nothing()
+        || err.is_partial_file()
+}
+
+fn retryable_code(code: u32) -> bool {
+    (500..600).contains(&code)
+}
+
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_millis(BACKOFF_BASE_MS << attempt.min(10))
+}
+
+pub fn send_receive(data: &mut Vec<u8>, url: &str, http: &Http) {
+    if let Err(err) = try_send_receive(data, url, http) {
+        println!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+/*
+ * Same as send_receive, failures are returned instead of exiting. Used
+ * where the process must not exit, e.g. from the terminal UI.
+ */
+pub fn try_send_receive(data: &mut Vec<u8>, url: &str, http: &Http) -> AnyhowResult<()> {
+    log::debug!("url: {}", url);
+    let mut attempt: u32 = 0;
+
+    loop {
+        let easy = new_easy(url, http)?;
+
+        let retry = match easy.perform() {
+            Ok(()) => {
+                let code = easy.response_code()?;
+                if retryable_code(code) && attempt < http.retries {
+                    log::warn!("Response code: {code}, retrying {url}");
+                    true
+                } else {
+                    data.extend_from_slice(&easy.get_ref().0);
+                    check_response(url, code, data)?;
+                    false
+                }
+            }
+            Err(err) if retryable_error(&err) && attempt < http.retries => {
+                log::warn!("Error: {err}, retrying {url}");
+                true
+            }
+            Err(err) => anyhow::bail!("GET {url} failed: {err}"),
+        };
+
+        if !retry {
+            return Ok(());
+        }
+        thread::sleep(backoff(attempt));
+        attempt += 1;
+    }
+}
+
+/*
+ * Fetch an url that may not exist, e.g. an optional log file. Returns false
+ * on 404 instead of exiting, other failures are errors.
+ */
+pub fn send_receive_optional(data: &mut Vec<u8>, url: &str, http: &Http) -> AnyhowResult<bool> {
     log::debug!("url: {}", url);
-    let mut easy = Easy::new();
-
-    easy.url(url).unwrap();
-    {
-        let mut transfer = easy.transfer();
-        transfer
-            .write_function(|bits| {
-                data.extend_from_slice(bits);
-                Ok(bits.len())
-            })
-            .unwrap();
-        transfer.perform().unwrap();
-    }
-
-    match easy.response_code() {
-        Ok(code) => match code {
-            200 => log::debug!("Response code: {code}"),
-            _ => {
-                println!("Response code: {}", code);
-                let message: String = serde_json::from_slice(data).unwrap();
-                println!("{:#?}", message);
-                process::exit(1);
+    let mut easy = new_easy(url, http)?;
+    // Log servers often serve compressed files with a content encoding
+    easy.accept_encoding("")?;
+    easy.perform()?;
+
+    let code = easy.response_code()?;
+    match code {
+        200 => {
+            data.extend_from_slice(&easy.get_ref().0);
+            Ok(true)
+        }
+        404 => {
+            log::debug!("Not found: {url}");
+            Ok(false)
+        }
+        _ => anyhow::bail!("GET {url} failed, response code: {code}"),
+    }
+}
+
+/*
+ * Send an authenticated request that changes state, e.g. POST or DELETE.
+ * Never retried as the request may not be idempotent.
+ */
+pub fn send_action(
+    data: &mut Vec<u8>,
+    method: &str,
+    url: &str,
+    body: Option<&serde_json::Value>,
+    http: &Http,
+    token: &str,
+) -> AnyhowResult<()> {
+    log::debug!("{method} url: {}", url);
+    let mut easy = new_easy(url, http)?;
+
+    let mut headers = List::new();
+    headers.append(&format!("Authorization: Bearer {token}"))?;
+    headers.append("Content-Type: application/json")?;
+    easy.http_headers(headers)?;
+
+    match body {
+        Some(body) => {
+            log::debug!("body: {body}");
+            easy.post(true)?;
+            easy.post_fields_copy(&serde_json::to_vec(body)?)?;
+        }
+        None => easy.post_fields_copy(&[])?,
+    }
+    easy.custom_request(method)?;
+    easy.perform()?;
+
+    let code = easy.response_code()?;
+    data.extend_from_slice(&easy.get_ref().0);
+    match code {
+        200..=299 => log::debug!("Response code: {code}"),
+        _ => anyhow::bail!(
+            "{method} {url} failed, response code: {code}: {}",
+            String::from_utf8_lossy(data)
+        ),
+    }
+    Ok(())
+}
+
+/*
+ * Fetch all urls using at most `parallel` concurrent transfers. The
+ * returned bodies are in the same order as the urls.
+ */
+pub fn send_receive_many(
+    urls: &[String],
+    parallel: usize,
+    http: &Http,
+) -> AnyhowResult<Vec<Vec<u8>>> {
+    let results = transfer_many(urls, parallel, http, false)?;
+    Ok(results.into_iter().map(Option::unwrap_or_default).collect())
+}
+
+/*
+ * Same as send_receive_many for urls that may not exist, e.g. the logs of
+ * old builds. A 404 is None instead of failing every transfer.
+ */
+pub fn send_receive_many_optional(
+    urls: &[String],
+    parallel: usize,
+    http: &Http,
+) -> AnyhowResult<Vec<Option<Vec<u8>>>> {
+    transfer_many(urls, parallel, http, true)
+}
+
+fn transfer_many(
+    urls: &[String],
+    parallel: usize,
+    http: &Http,
+    optional: bool,
+) -> AnyhowResult<Vec<Option<Vec<u8>>>> {
+    log::debug!("Fan-out {} requests, parallel: {}", urls.len(), parallel);
+    let multi = Multi::new();
+    let mut results: Vec<Option<Vec<u8>>> = vec![None; urls.len()];
+    let now = Instant::now();
+    // (url index, attempt, not before)
+    let mut pending: VecDeque<(usize, u32, Instant)> =
+        (0..urls.len()).map(|index| (index, 0, now)).collect();
+    let mut active: HashMap<usize, (u32, Easy2Handle<Collector>)> = HashMap::new();
+
+    loop {
+        // Keep the pipe full up to the parallelism limit
+        let mut waiting: VecDeque<(usize, u32, Instant)> = VecDeque::new();
+        while active.len() < parallel.max(1) {
+            let Some((index, attempt, ready)) = pending.pop_front() else {
+                break;
+            };
+            if ready > Instant::now() {
+                waiting.push_back((index, attempt, ready));
+                continue;
+            }
+            log::debug!("url: {}", urls[index]);
+            let mut handle = multi.add2(new_easy(&urls[index], http)?)?;
+            handle.set_token(index)?;
+            active.insert(index, (attempt, handle));
+        }
+        pending.extend(waiting);
+
+        if active.is_empty() {
+            match pending.iter().map(|(_, _, ready)| *ready).min() {
+                Some(ready) => {
+                    thread::sleep(ready.saturating_duration_since(Instant::now()));
+                    continue;
+                }
+                None => break,
+            }
+        }
+
+        multi.perform()?;
+
+        let mut finished: Vec<(usize, Result<(), curl::Error>)> = vec![];
+        multi.messages(|message| {
+            if let (Ok(token), Some(result)) = (message.token(), message.result()) {
+                finished.push((token, result));
+            }
+        });
+
+        if finished.is_empty() {
+            multi.wait(&mut [], Duration::from_millis(500))?;
+            continue;
+        }
+
+        for (index, result) in finished {
+            let (attempt, handle) = active.remove(&index).unwrap();
+            let mut easy = multi.remove2(handle)?;
+            let retry = match result {
+                Ok(()) => retryable_code(easy.response_code()?),
+                Err(ref err) => retryable_error(err),
+            };
+
+            if retry && attempt < http.retries {
+                log::warn!("Request failed, retrying {}", urls[index]);
+                pending.push_back((index, attempt + 1, Instant::now() + backoff(attempt)));
+                continue;
+            }
+
+            result?;
+            let code = easy.response_code()?;
+            if optional && code == 404 {
+                log::debug!("Not found: {}", urls[index]);
+                continue;
             }
-        },
-        Err(err) => panic!("Error: {}", err),
+            let data = std::mem::take(&mut easy.get_mut().0);
+            check_response(&urls[index], code, &data)?;
+            results[index] = Some(data);
+        }
+    }
+
+    Ok(results)
+}
+
+/*
+ * Zuul sends the error message as a JSON string, anything else is shown
+ * as is
+ */
+fn check_response(url: &str, code: u32, data: &[u8]) -> AnyhowResult<()> {
+    match code {
+        200 => {
+            log::debug!("Response code: {code}");
+            Ok(())
+        }
+        _ => {
+            let message = match serde_json::from_slice::<String>(data) {
+                Ok(message) => message,
+                Err(_) => String::from_utf8_lossy(data).to_string(),
+            };
+            anyhow::bail!("GET {url} failed, response code: {code}: {message}")
+        }
     }
 }
@@ -1,5 +1,6 @@
 use std::fs;
 use std::io;
+use std::io::Write;
 use std::path::Path;
 
 pub fn remove_dir_files<P: AsRef<Path>>(path: P) -> io::Result<()> {
@@ -15,3 +16,15 @@ pub fn remove_dir_files<P: AsRef<Path>>(path: P) -> io::Result<()> {
     }
     Ok(())
 }
+
+/*
+ * Ask a yes/no question on stdin, anything but y/Y is a no. The question
+ * goes to stderr to keep stdout for the command output.
+ */
+pub fn confirm(question: &str) -> io::Result<bool> {
+    eprint!("{question} [y/n] ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y"))
+}
@@ -0,0 +1,264 @@
+use crate::util::duration::parse_duration;
+use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer};
+
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/*
+ * Parse a Zuul timestamp. Zuul sends naive UTC times like
+ * `2024-05-02T10:11:12`, RFC 3339 is accepted as well.
+ */
+pub fn parse(value: &str) -> AnyhowResult<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(naive.and_utc());
+        }
+    }
+    anyhow::bail!("Invalid timestamp: {value}")
+}
+
+/*
+ * serde deserialize_with for optional timestamps, a malformed timestamp is
+ * logged and dropped instead of failing the whole response
+ */
+pub fn deserialize_option<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    Ok(value.and_then(|value| match parse(&value) {
+        Ok(datetime) => Some(datetime),
+        Err(err) => {
+            log::warn!("{err}");
+            None
+        }
+    }))
+}
+
+/*
+ * serde deserialize_with for optional epoch timestamps in seconds
+ */
+pub fn deserialize_epoch_option<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<f64> = Option::deserialize(deserializer)?;
+    Ok(value.and_then(|secs| DateTime::from_timestamp_millis((secs * 1000.0) as i64)))
+}
+
+/*
+ * How a time relates to now: `5 min ago`, `in 2 hours` or `just now`
+ */
+pub fn relative(datetime: &DateTime<Utc>, now: &DateTime<Utc>) -> String {
+    let delta = *now - *datetime;
+    let secs = delta.num_seconds().abs();
+    let amount = match secs {
+        0..=59 => return "just now".to_string(),
+        60..=3599 => format!("{} min", secs / 60),
+        3600..=86399 => plural(secs / 3600, "hour"),
+        _ => plural(secs / 86400, "day"),
+    };
+    match delta >= TimeDelta::zero() {
+        true => format!("{amount} ago"),
+        false => format!("in {amount}"),
+    }
+}
+
+fn plural(amount: i64, unit: &str) -> String {
+    match amount {
+        1 => format!("1 {unit}"),
+        _ => format!("{amount} {unit}s"),
+    }
+}
+
+/*
+ * Timezone the timestamps are displayed in
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Zone {
+    Utc,
+    Local,
+    Named(Tz),
+}
+
+impl Zone {
+    /*
+     * `utc`, `local` or an IANA name like `Europe/Paris`
+     */
+    pub fn parse(value: &str) -> AnyhowResult<Zone> {
+        match value.to_lowercase().as_str() {
+            "utc" => Ok(Zone::Utc),
+            "local" => Ok(Zone::Local),
+            _ => match value.parse::<Tz>() {
+                Ok(tz) => Ok(Zone::Named(tz)),
+                Err(_) => {
+                    anyhow::bail!("Invalid timezone: {value}, expected utc, local or an IANA name")
+                }
+            },
+        }
+    }
+
+    pub fn format(&self, datetime: &DateTime<Utc>) -> String {
+        match self {
+            Zone::Utc => datetime.format(FORMAT).to_string(),
+            Zone::Local => datetime.with_timezone(&Local).format(FORMAT).to_string(),
+            Zone::Named(tz) => datetime.with_timezone(tz).format(FORMAT).to_string(),
+        }
+    }
+
+    /*
+     * Same as format, `N/A` when unknown
+     */
+    pub fn format_option(&self, datetime: Option<&DateTime<Utc>>) -> String {
+        match datetime {
+            Some(datetime) => self.format(datetime),
+            None => "N/A".to_string(),
+        }
+    }
+}
+
+/*
+ * Bound of a time window: a duration back from now like `2d` or `12h`, a
+ * date like `2026-10-01` (midnight UTC) or a full timestamp
+ */
+pub fn parse_bound(value: &str) -> AnyhowResult<DateTime<Utc>> {
+    if let Ok(duration) = parse_duration(value) {
+        return match Utc::now().checked_sub_signed(duration) {
+            Some(datetime) => Ok(datetime),
+            None => anyhow::bail!("Invalid time: {value} ago is out of range"),
+        };
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+    match parse(value) {
+        Ok(datetime) => Ok(datetime),
+        Err(_) => anyhow::bail!(
+            "Invalid time: {value}, expected a duration like 2d, a date or a timestamp"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 2, h, m, s).unwrap()
+    }
+
+    #[test]
+    fn parse_forms() {
+        assert_eq!(parse("2024-05-02T10:11:12").unwrap(), at(10, 11, 12));
+        assert_eq!(parse("2024-05-02 10:11:12").unwrap(), at(10, 11, 12));
+        assert_eq!(parse(" 2024-05-02T10:11:12Z ").unwrap(), at(10, 11, 12));
+        assert_eq!(parse("2024-05-02T12:11:12+02:00").unwrap(), at(10, 11, 12));
+        assert_eq!(
+            parse("2024-05-02T10:11:12.500").unwrap(),
+            at(10, 11, 12) + TimeDelta::milliseconds(500)
+        );
+    }
+
+    #[test]
+    fn parse_rejects() {
+        assert!(parse("").is_err());
+        assert!(parse("2024-05-02").is_err());
+        assert!(parse("2024-13-02T10:11:12").is_err());
+        assert!(parse("yesterday").is_err());
+    }
+
+    #[derive(Deserialize)]
+    struct Build {
+        #[serde(default, deserialize_with = "deserialize_option")]
+        start_time: Option<DateTime<Utc>>,
+        #[serde(default, deserialize_with = "deserialize_epoch_option")]
+        state_time: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn deserialize_lenient() {
+        let build: Build = serde_json::from_str(
+            r#"{"start_time": "2024-05-02T10:11:12", "state_time": 1714644672.0}"#,
+        )
+        .unwrap();
+        assert_eq!(build.start_time, Some(at(10, 11, 12)));
+        assert_eq!(build.state_time, Some(at(10, 11, 12)));
+
+        let build: Build = serde_json::from_str(r#"{"start_time": "garbage"}"#).unwrap();
+        assert_eq!(build.start_time, None);
+        let build: Build = serde_json::from_str(r#"{"start_time": null}"#).unwrap();
+        assert_eq!(build.start_time, None);
+        assert_eq!(build.state_time, None);
+    }
+
+    #[test]
+    fn relative_boundaries() {
+        let now = at(12, 0, 0);
+        let ago = |secs| relative(&(now - TimeDelta::seconds(secs)), &now);
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(-59), "just now");
+        assert_eq!(ago(60), "1 min ago");
+        assert_eq!(ago(3599), "59 min ago");
+        assert_eq!(ago(3600), "1 hour ago");
+        assert_eq!(ago(7200), "2 hours ago");
+        assert_eq!(ago(86400), "1 day ago");
+        assert_eq!(ago(-7200), "in 2 hours");
+    }
+
+    #[test]
+    fn zone_parse() {
+        assert_eq!(Zone::parse("utc").unwrap(), Zone::Utc);
+        assert_eq!(Zone::parse("UTC").unwrap(), Zone::Utc);
+        assert_eq!(Zone::parse("Local").unwrap(), Zone::Local);
+        assert_eq!(
+            Zone::parse("Europe/Paris").unwrap(),
+            Zone::Named(chrono_tz::Europe::Paris)
+        );
+        assert!(Zone::parse("Europe/Nowhere").is_err());
+        assert!(Zone::parse("").is_err());
+    }
+
+    #[test]
+    fn zone_format() {
+        let datetime = at(10, 11, 12);
+        assert_eq!(Zone::Utc.format(&datetime), "2024-05-02 10:11:12");
+        assert_eq!(
+            Zone::parse("Europe/Paris").unwrap().format(&datetime),
+            "2024-05-02 12:11:12"
+        );
+        assert_eq!(Zone::Utc.format_option(None), "N/A");
+    }
+
+    #[test]
+    fn parse_bound_forms() {
+        let before = Utc::now();
+        let bound = parse_bound("2d").unwrap();
+        let after = Utc::now();
+        assert!(bound >= before - TimeDelta::days(2));
+        assert!(bound <= after - TimeDelta::days(2));
+
+        assert_eq!(
+            parse_bound("2024-05-02").unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap()
+        );
+        assert_eq!(parse_bound("2024-05-02T10:11:12").unwrap(), at(10, 11, 12));
+        assert_eq!(parse_bound("2024-05-02T10:11:12Z").unwrap(), at(10, 11, 12));
+    }
+
+    #[test]
+    fn parse_bound_rejects() {
+        assert!(parse_bound("").is_err());
+        assert!(parse_bound("2d ago").is_err());
+        assert!(parse_bound("2024-05").is_err());
+        assert!(parse_bound("2024-02-30").is_err());
+        assert!(parse_bound("20000000w").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use serde_yaml::Value;
+
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some("null".to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::String(value) => Some(value.clone()),
+        Value::Sequence(seq) if seq.is_empty() => Some("[]".to_string()),
+        Value::Mapping(map) if map.is_empty() => Some("{}".to_string()),
+        Value::Tagged(tagged) => scalar(&tagged.value),
+        _ => None,
+    }
+}
+
+fn key(value: &Value) -> String {
+    scalar(value).unwrap_or_else(|| "?".to_string())
+}
+
+fn children(value: &Value) -> Vec<(String, &Value)> {
+    match value {
+        Value::Mapping(map) => map.iter().map(|(k, v)| (key(k), v)).collect(),
+        Value::Sequence(seq) => seq
+            .iter()
+            .enumerate()
+            .map(|(index, v)| (format!("[{index}]"), v))
+            .collect(),
+        Value::Tagged(tagged) => children(&tagged.value),
+        _ => vec![],
+    }
+}
+
+fn walk(value: &Value, prefix: &str, out: &mut String) {
+    let entries = children(value);
+    let last = entries.len().saturating_sub(1);
+    for (index, (name, child)) in entries.into_iter().enumerate() {
+        let (branch, indent) = match index == last {
+            true => ("└─ ", "   "),
+            false => ("├─ ", "│  "),
+        };
+        match scalar(child) {
+            Some(leaf) => out.push_str(&format!("{prefix}{branch}{name}: {leaf}\n")),
+            None => {
+                out.push_str(&format!("{prefix}{branch}{name}\n"));
+                walk(child, &format!("{prefix}{indent}"), out);
+            }
+        }
+    }
+}
+
+/*
+ * Render a value as an indented tree, one key per line
+ */
+pub fn render(value: &Value) -> String {
+    if let Some(leaf) = scalar(value) {
+        return format!("{leaf}\n");
+    }
+    let mut out = String::new();
+    walk(value, "", &mut out);
+    out
+}
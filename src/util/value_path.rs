@@ -0,0 +1,118 @@
+use anyhow::Result as AnyhowResult;
+use serde_yaml::Value;
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/*
+ * Split a jq-like path such as `.zuul.items[0].project` or `zuul.items.0`
+ * into keys and sequence indexes
+ */
+fn parse(path: &str) -> AnyhowResult<Vec<Segment>> {
+    let mut segments: Vec<Segment> = vec![];
+    for part in path.trim_start_matches('.').split('.') {
+        if part.is_empty() {
+            continue;
+        }
+        let (key, mut rest) = match part.find('[') {
+            Some(pos) => part.split_at(pos),
+            None => (part, ""),
+        };
+        if !key.is_empty() {
+            segments.push(Segment::Key(key.to_string()));
+        }
+        while let Some(stripped) = rest.strip_prefix('[') {
+            let Some(end) = stripped.find(']') else {
+                anyhow::bail!("Unclosed [ in path: {path}");
+            };
+            segments.push(Segment::Index(stripped[..end].parse()?));
+            rest = &stripped[end + 1..];
+        }
+        if !rest.is_empty() {
+            anyhow::bail!("Invalid path: {path}");
+        }
+    }
+    Ok(segments)
+}
+
+/*
+ * Select the value at path, None if any segment is missing
+ */
+pub fn select<'a>(value: &'a Value, path: &str) -> AnyhowResult<Option<&'a Value>> {
+    let mut current = value;
+    for segment in parse(path)? {
+        let next = match (&segment, current) {
+            (Segment::Index(index), Value::Sequence(seq)) => seq.get(*index),
+            (Segment::Key(key), Value::Sequence(seq)) => match key.parse::<usize>() {
+                Ok(index) => seq.get(index),
+                Err(_) => None,
+            },
+            (Segment::Key(key), Value::Mapping(map)) => map.get(key.as_str()),
+            _ => None,
+        };
+        match next {
+            Some(value) => current = value,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> Value {
+        serde_yaml::from_str(
+            "zuul:\n  items:\n    - project: {name: org/a}\n    - project: {name: org/b}\n  branch: main\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_segments() {
+        assert_eq!(
+            parse(".zuul.items[0].project").unwrap(),
+            vec![
+                Segment::Key("zuul".to_string()),
+                Segment::Key("items".to_string()),
+                Segment::Index(0),
+                Segment::Key("project".to_string()),
+            ]
+        );
+        assert_eq!(parse("a[1][2]").unwrap().len(), 3);
+        assert!(parse(".").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        assert!(parse("items[0").is_err());
+        assert!(parse("items[x]").is_err());
+        assert!(parse("items[0]x").is_err());
+    }
+
+    #[test]
+    fn select_keys_and_indexes() {
+        let vars = vars();
+        let name = |path| {
+            select(&vars, path)
+                .unwrap()
+                .and_then(|value| value.as_str())
+        };
+        assert_eq!(name(".zuul.branch"), Some("main"));
+        assert_eq!(name(".zuul.items[1].project.name"), Some("org/b"));
+        assert_eq!(name("zuul.items.0.project.name"), Some("org/a"));
+        assert_eq!(select(&vars, "").unwrap(), Some(&vars));
+    }
+
+    #[test]
+    fn select_missing() {
+        let vars = vars();
+        assert_eq!(select(&vars, ".zuul.change").unwrap(), None);
+        assert_eq!(select(&vars, ".zuul.items[2]").unwrap(), None);
+        assert_eq!(select(&vars, ".zuul.branch.name").unwrap(), None);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::config::Http;
+use crate::util::easy::send_receive_many;
+use anyhow::Result as AnyhowResult;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+// Results per request when paging through a time window
+const PAGE: usize = 100;
+
+// Requests before giving up on reaching the window
+const MAX_PAGES: usize = 50;
+
+/*
+ * Time bounds given by --since and --until, both inclusive
+ */
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    pub fn new(since: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> TimeWindow {
+        TimeWindow { since, until }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /*
+     * Results without time are only kept when no bound is set
+     */
+    pub fn contains(&self, time: Option<&DateTime<Utc>>) -> bool {
+        let Some(time) = time else {
+            return !self.is_set();
+        };
+        self.since.is_none_or(|since| *time >= since)
+            && self.until.is_none_or(|until| *time <= until)
+    }
+
+    fn older(&self, time: Option<&DateTime<Utc>>) -> bool {
+        matches!((self.since, time), (Some(since), Some(time)) if *time < since)
+    }
+
+    /*
+     * Zuul returns the newest results first. Pages of every url are requested
+     * together until they are older than since or `limit` results are in the
+     * window, at most MAX_PAGES of them. Results are in the order of the urls.
+     */
+    pub fn fetch<T, F>(
+        &self,
+        urls: &[String],
+        limit: Option<usize>,
+        parallel: usize,
+        http: &Http,
+        time: F,
+    ) -> AnyhowResult<Vec<Vec<T>>>
+    where
+        T: DeserializeOwned,
+        F: Fn(&T) -> Option<DateTime<Utc>>,
+    {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut results: Vec<Vec<T>> = urls.iter().map(|_| vec![]).collect();
+        // Indexes of the urls with pages left
+        let mut active: Vec<usize> = (0..urls.len()).collect();
+
+        for page_index in 0..MAX_PAGES {
+            if active.is_empty() {
+                return Ok(results);
+            }
+            let page_urls: Vec<String> = active
+                .iter()
+                .map(|index| {
+                    let separator = match urls[*index].contains('?') {
+                        true => '&',
+                        false => '?',
+                    };
+                    format!(
+                        "{}{separator}limit={PAGE}&skip={}",
+                        urls[*index],
+                        page_index * PAGE
+                    )
+                })
+                .collect();
+            let bodies = send_receive_many(&page_urls, parallel, http)?;
+
+            let mut remaining: Vec<usize> = vec![];
+            for (index, body) in active.iter().zip(bodies) {
+                let page: Vec<T> = serde_json::from_slice(&body)?;
+                let last = page.len() < PAGE
+                    || page
+                        .last()
+                        .is_some_and(|item| self.older(time(item).as_ref()));
+                let result = &mut results[*index];
+                result.extend(
+                    page.into_iter()
+                        .filter(|item| self.contains(time(item).as_ref())),
+                );
+                result.truncate(limit);
+                if !last && result.len() < limit {
+                    remaining.push(*index);
+                }
+            }
+            active = remaining;
+        }
+
+        for index in active {
+            eprintln!(
+                "Stopped {} after {} results with {} in the window, set --since to bound it",
+                urls[index],
+                MAX_PAGES * PAGE,
+                results[index].len()
+            );
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, TimeZone};
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn contains_inclusive_bounds() {
+        let window = TimeWindow::new(Some(day(10)), Some(day(12)));
+        assert!(window.contains(Some(&day(10))));
+        assert!(window.contains(Some(&day(11))));
+        assert!(window.contains(Some(&day(12))));
+        assert!(!window.contains(Some(&(day(10) - TimeDelta::seconds(1)))));
+        assert!(!window.contains(Some(&(day(12) + TimeDelta::seconds(1)))));
+    }
+
+    #[test]
+    fn contains_open_bounds() {
+        let since = TimeWindow::new(Some(day(10)), None);
+        assert!(since.contains(Some(&day(30))));
+        assert!(!since.contains(Some(&day(9))));
+
+        let until = TimeWindow::new(None, Some(day(10)));
+        assert!(until.contains(Some(&day(1))));
+        assert!(!until.contains(Some(&day(11))));
+    }
+
+    #[test]
+    fn contains_without_time() {
+        assert!(TimeWindow::default().contains(None));
+        assert!(TimeWindow::default().contains(Some(&day(1))));
+        assert!(!TimeWindow::new(Some(day(10)), None).contains(None));
+        assert!(!TimeWindow::new(None, Some(day(10))).contains(None));
+    }
+
+    #[test]
+    fn older_than_since() {
+        let window = TimeWindow::new(Some(day(10)), None);
+        assert!(window.older(Some(&day(9))));
+        assert!(!window.older(Some(&day(10))));
+        assert!(!window.older(None));
+        assert!(!TimeWindow::new(None, Some(day(10))).older(Some(&day(1))));
+    }
+}